@@ -0,0 +1,78 @@
+use super::{ Interval, IntervalSetError };
+
+/// An interval that is guaranteed to be bounded on both sides.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundedInterval<T: PartialOrd + Clone>(Interval<T>);
+
+impl<T: PartialOrd + Clone> BoundedInterval<T> {
+    /// Gets the low value of the interval.
+    pub fn low(&self) -> T {
+        // It is safe to unwrap since the interval is bounded
+        self.0.low().unwrap()
+    }
+
+    /// Gets the high value of the interval.
+    pub fn high(&self) -> T {
+        // It is safe to unwrap since the interval is bounded
+        self.0.high().unwrap()
+    }
+
+    /// Gets a reference to the underlying interval.
+    pub fn as_interval(&self) -> &Interval<T> {
+        &self.0
+    }
+
+    /// Converts back to the underlying interval.
+    pub fn into_interval(self) -> Interval<T> {
+        self.0
+    }
+}
+
+impl<T: PartialOrd + Clone> TryFrom<Interval<T>> for BoundedInterval<T> {
+    type Error = IntervalSetError;
+
+    fn try_from(interval: Interval<T>) -> Result<Self, Self::Error> {
+        if interval.is_bounded() {
+            Ok(Self(interval))
+        } else {
+            Err(IntervalSetError::UnboundedInterval)
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> From<BoundedInterval<T>> for Interval<T> {
+    fn from(bounded_interval: BoundedInterval<T>) -> Self {
+        bounded_interval.into_interval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let interval = Interval::<i32>::open_closed(0, 1).unwrap();
+        let bounded_interval = BoundedInterval::try_from(interval.clone());
+        assert!(bounded_interval.is_ok());
+        assert_eq!(bounded_interval.unwrap().into_interval(), interval);
+
+        let bounded_interval = BoundedInterval::try_from(Interval::<i32>::unbounded_open(0));
+        assert!(bounded_interval.is_err());
+
+        let bounded_interval = BoundedInterval::try_from(Interval::<i32>::closed_unbounded(0));
+        assert!(bounded_interval.is_err());
+
+        let bounded_interval = BoundedInterval::try_from(Interval::<i32>::universe());
+        assert!(bounded_interval.is_err());
+    }
+
+    #[test]
+    fn test_low_and_high() {
+        let bounded_interval = BoundedInterval::try_from(Interval::<i32>::open(-1, 3).unwrap()).unwrap();
+        let low: i32 = bounded_interval.low();
+        let high: i32 = bounded_interval.high();
+        assert_eq!(low, -1);
+        assert_eq!(high, 3);
+    }
+}
@@ -0,0 +1,187 @@
+use num_traits::{ FromBytes, ToBytes };
+use super::{ Endpoint, Interval, IntervalSet, IntervalSetError, IntervalSetResult };
+
+/// Tags of the endpoints in the binary format.
+const OPEN: u8 = 0;
+const CLOSED: u8 = 1;
+const UNBOUNDED: u8 = 2;
+
+impl<T> IntervalSet<T>
+    where
+        T: PartialOrd + Clone + ToBytes + FromBytes,
+        for<'a> <T as FromBytes>::Bytes: Sized + TryFrom<&'a [u8]>
+{
+    /// Serializes the set into a compact binary format:
+    /// the number of intervals as a little-endian `u64`,
+    /// followed by the left and right endpoints of each interval,
+    /// where each endpoint is a tag byte followed by the little-endian value if bounded.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(self.intervals.len() as u64).to_le_bytes());
+
+        for interval in &self.intervals {
+            for endpoint in [interval.left(), interval.right()] {
+                match endpoint {
+                    Endpoint::Open(value) => {
+                        bytes.push(OPEN);
+                        bytes.extend_from_slice(value.to_le_bytes().as_ref());
+                    }
+                    Endpoint::Closed(value) => {
+                        bytes.push(CLOSED);
+                        bytes.extend_from_slice(value.to_le_bytes().as_ref());
+                    }
+                    Endpoint::Unbounded => bytes.push(UNBOUNDED),
+                }
+            }
+        }
+
+        bytes
+    }
+
+    /// Deserializes a set written by [`IntervalSet::to_bytes`].
+    /// Returns an error if the bytes are malformed
+    /// or do not describe valid, sorted and separated intervals.
+    pub fn from_bytes(bytes: &[u8]) -> IntervalSetResult<Self> {
+        let mut reader = Reader { bytes };
+
+        let count = u64::from_le_bytes(reader.read_array()?);
+
+        // Do not trust the count for preallocation
+        let mut intervals = Vec::new();
+        for _ in 0..count {
+            let left = reader.read_endpoint()?;
+            let right = reader.read_endpoint()?;
+            intervals.push(Interval::new(left, right)?);
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(IntervalSetError::InvalidBytes);
+        }
+
+        let set = Self { intervals };
+        set.validate()?;
+
+        Ok(set)
+    }
+}
+
+/// Reads values from the front of the bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Reads the next `n` bytes.
+    fn read(&mut self, n: usize) -> IntervalSetResult<&'a [u8]> {
+        if self.bytes.len() < n {
+            return Err(IntervalSetError::InvalidBytes);
+        }
+
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+
+        Ok(head)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> IntervalSetResult<[u8; N]> {
+        let head = self.read(N)?;
+
+        // It is safe to unwrap since the length is exactly N
+        Ok(head.try_into().unwrap())
+    }
+
+    fn read_endpoint<T>(&mut self) -> IntervalSetResult<Endpoint<T>>
+        where
+            T: PartialOrd + Clone + FromBytes,
+            <T as FromBytes>::Bytes: Sized + TryFrom<&'a [u8]>
+    {
+        let [tag] = self.read_array()?;
+        if tag == UNBOUNDED {
+            return Ok(Endpoint::Unbounded);
+        }
+
+        let value = self.read(std::mem::size_of::<<T as FromBytes>::Bytes>())?;
+        let value = <T as FromBytes>::Bytes
+            ::try_from(value)
+            .map_err(|_| IntervalSetError::InvalidBytes)?;
+        let value = T::from_le_bytes(&value);
+
+        match tag {
+            OPEN => Ok(Endpoint::Open(value)),
+            CLOSED => Ok(Endpoint::Closed(value)),
+            _ => Err(IntervalSetError::InvalidBytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let set = IntervalSet::<i32>::unbounded_open(-10)
+            .union(&IntervalSet::closed(0, 0).unwrap())
+            .union(&IntervalSet::open_closed(3, 7).unwrap())
+            .union(&IntervalSet::closed_unbounded(100));
+        let bytes = set.to_bytes();
+        // The count, two unbounded endpoints, and six bounded endpoints
+        assert_eq!(bytes.len(), 8 + 2 + 6 * (1 + 4));
+        assert_eq!(IntervalSet::<i32>::from_bytes(&bytes).unwrap().intervals, set.intervals);
+
+        let set = IntervalSet::<f64>::open(0.5, 1.5).unwrap();
+        assert_eq!(IntervalSet::<f64>::from_bytes(&set.to_bytes()).unwrap().intervals, set.intervals);
+
+        let set = IntervalSet::<u8>::closed(1, 2)
+            .unwrap()
+            .intersection(&IntervalSet::closed(5, 6).unwrap());
+        assert_eq!(set.to_bytes(), vec![0; 8]);
+        assert!(IntervalSet::<u8>::from_bytes(&set.to_bytes()).unwrap().intervals.is_empty());
+    }
+
+    #[test]
+    fn corrupted_bytes() {
+        let set = IntervalSet::<i32>::closed(0, 5)
+            .unwrap()
+            .union(&IntervalSet::closed(8, 9).unwrap());
+        let bytes = set.to_bytes();
+
+        // Truncated
+        let error = IntervalSet::<i32>::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidBytes);
+        let error = IntervalSet::<i32>::from_bytes(&[]).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidBytes);
+
+        // Trailing bytes
+        let mut corrupted = bytes.clone();
+        corrupted.push(0);
+        let error = IntervalSet::<i32>::from_bytes(&corrupted).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidBytes);
+
+        // Unknown tag
+        let mut corrupted = bytes.clone();
+        corrupted[8] = 7;
+        let error = IntervalSet::<i32>::from_bytes(&corrupted).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidBytes);
+
+        // Huge count
+        let mut corrupted = bytes.clone();
+        corrupted[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let error = IntervalSet::<i32>::from_bytes(&corrupted).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidBytes);
+
+        // The left value is greater than the right value
+        let mut corrupted = bytes.clone();
+        corrupted[9..13].copy_from_slice(&6_i32.to_le_bytes());
+        let error = IntervalSet::<i32>::from_bytes(&corrupted).unwrap_err();
+        assert_eq!(error, IntervalSetError::InvalidInterval);
+
+        // The intervals overlap
+        let mut corrupted = bytes.clone();
+        corrupted[19..23].copy_from_slice(&4_i32.to_le_bytes());
+        assert_eq!(
+            IntervalSet::<i32>::from_bytes(&corrupted).unwrap_err(),
+            IntervalSetError::UnnormalizedIntervalAt { index: 0 }
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use chrono::{ Duration, NaiveDateTime };
+use super::{ Interval, IntervalSet, IntervalSetResult };
+
+impl Interval<NaiveDateTime> {
+    /// Creates a left-closed right-open interval from the start to the end date time.
+    pub fn from_datetime_range(start: NaiveDateTime, end: NaiveDateTime) -> IntervalSetResult<Self> {
+        Self::closed_open(start, end)
+    }
+
+    /// Gets the duration of the interval, i.e., its measure in time.
+    /// If the interval is unbounded, returns `None`.
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.high()? - self.low()?)
+    }
+}
+
+impl IntervalSet<NaiveDateTime> {
+    /// Gets the total duration of the intervals in the set.
+    /// If any interval is unbounded, returns `None`.
+    pub fn duration(&self) -> Option<Duration> {
+        self.intervals
+            .iter()
+            .try_fold(Duration::zero(), |total, interval| Some(total + interval.duration()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use super::*;
+
+    fn datetime(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_duration() {
+        let interval = Interval::from_datetime_range(datetime(1, 0), datetime(2, 0)).unwrap();
+        assert_eq!(interval.duration(), Some(Duration::hours(24)));
+        assert!(interval.contains(&datetime(1, 12)));
+        assert!(!interval.contains(&datetime(2, 0)));
+
+        let interval = Interval::closed_unbounded(datetime(1, 0));
+        assert_eq!(interval.duration(), None);
+
+        assert!(Interval::from_datetime_range(datetime(2, 0), datetime(1, 0)).is_err());
+
+        let set = IntervalSet::try_from_intervals(vec![
+            Interval::from_datetime_range(datetime(1, 9), datetime(1, 12)).unwrap(),
+            Interval::from_datetime_range(datetime(1, 11), datetime(1, 13)).unwrap(),
+            Interval::from_datetime_range(datetime(2, 9), datetime(2, 10)).unwrap()
+        ]).unwrap();
+        assert_eq!(set.duration(), Some(Duration::hours(5)));
+    }
+}
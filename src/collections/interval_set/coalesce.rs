@@ -0,0 +1,320 @@
+use super::{ Interval, IntervalSet };
+
+/// The policy that decides which intervals are merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Merges the intervals that are not separated,
+    /// i.e., the intervals that overlap or touch, e.g., `(0, 1)` and `[1, 2]`.
+    #[default]
+    TopologicalClosure,
+
+    /// Merges only the intervals that share at least one point,
+    /// e.g., `[0, 1]` and `[1, 2]`, but not `(0, 1)` and `[1, 2]`.
+    Strict,
+}
+
+impl MergePolicy {
+    /// Checks if the two intervals should be merged under the policy.
+    pub fn should_merge<T: PartialOrd + Clone>(&self, a: &Interval<T>, b: &Interval<T>) -> bool {
+        match self {
+            MergePolicy::TopologicalClosure => !a.is_separated_from(b),
+            MergePolicy::Strict => a.overlaps(b),
+        }
+    }
+}
+
+/// Intervals sorted by their left endpoints and merged according to a [`MergePolicy`].
+///
+/// Under [`MergePolicy::Strict`], touching intervals such as `(0, 1)` and `[1, 2]` are kept apart,
+/// so unlike an [`IntervalSet`], the intervals are only guaranteed to be sorted and disjoint,
+/// not separated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedIntervals<T: PartialOrd + Clone> {
+    intervals: Vec<Interval<T>>,
+    policy: MergePolicy,
+}
+
+impl<T: PartialOrd + Clone> MergedIntervals<T> {
+    /// Sorts the intervals by their left endpoints and merges them according to the policy.
+    pub fn new(intervals: impl IntoIterator<Item = Interval<T>>, policy: MergePolicy) -> Self {
+        let mut intervals: Vec<Interval<T>> = intervals.into_iter().collect();
+        intervals.sort_by(|a, b| a.cmp_left_endpoints(b));
+
+        Self::from_sorted(intervals, policy)
+    }
+
+    /// Merges the intervals according to the policy.
+    /// The intervals must be sorted by their left endpoints.
+    pub(crate) fn from_sorted(intervals: Vec<Interval<T>>, policy: MergePolicy) -> Self {
+        Self {
+            intervals: intervals.into_iter().coalesce_intervals_with(policy).collect(),
+            policy,
+        }
+    }
+
+    /// Gets the policy the intervals are merged with.
+    pub fn policy(&self) -> MergePolicy {
+        self.policy
+    }
+
+    /// Gets the merged intervals in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Converts into the vector of the merged intervals.
+    pub fn into_intervals(self) -> Vec<Interval<T>> {
+        self.intervals
+    }
+
+    /// Converts into an interval set covering the same points,
+    /// merging the touching intervals that were kept apart.
+    pub fn into_set(self) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self.intervals.into_iter().coalesce_intervals().collect(),
+        }
+    }
+}
+
+/// Extension methods for iterators over intervals.
+pub trait IntervalIteratorExt<T: PartialOrd + Clone>: Iterator<Item = Interval<T>> + Sized {
+    /// Merges the intervals that are not separated on the fly.
+    /// The intervals must be sorted by their left endpoints.
+    fn coalesce_intervals(self) -> CoalesceIntervals<T, Self> {
+        self.coalesce_intervals_with(MergePolicy::default())
+    }
+
+    /// Merges the intervals on the fly according to the policy.
+    /// The intervals must be sorted by their left endpoints.
+    fn coalesce_intervals_with(self, policy: MergePolicy) -> CoalesceIntervals<T, Self> {
+        CoalesceIntervals {
+            iter: self,
+            policy,
+            pending: None,
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone, I: Iterator<Item = Interval<T>>> IntervalIteratorExt<T> for I {}
+
+/// An iterator that merges the intervals that are not separated.
+/// This struct is created by [`IntervalIteratorExt::coalesce_intervals`].
+pub struct CoalesceIntervals<T: PartialOrd + Clone, I: Iterator<Item = Interval<T>>> {
+    iter: I,
+
+    policy: MergePolicy,
+
+    /// The interval read ahead from the underlying iterator
+    /// that is separated from the previously yielded one.
+    pending: Option<Interval<T>>,
+}
+
+impl<T: PartialOrd + Clone, I: Iterator<Item = Interval<T>>> Iterator for CoalesceIntervals<T, I> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = self.pending.take().or_else(|| self.iter.next())?;
+
+        // Keep merging the following intervals until one that should not be merged is found
+        for interval in self.iter.by_ref() {
+            if !self.policy.should_merge(&current, &interval) {
+                self.pending = Some(interval);
+                break;
+            }
+
+            current = current.merge_unchecked(&interval);
+        }
+
+        Some(current)
+    }
+}
+
+/// Merges the intervals that are not separated in place in O(n) time,
+/// truncating the vector to the merged intervals.
+/// The intervals must be sorted by their left endpoints.
+pub fn coalesce_sorted<T: PartialOrd + Clone>(intervals: &mut Vec<Interval<T>>) {
+    if intervals.is_empty() {
+        return;
+    }
+
+    // intervals[..=last] are the merged intervals so far
+    let mut last = 0;
+    for i in 1..intervals.len() {
+        if intervals[last].is_separated_from(&intervals[i]) {
+            last += 1;
+            intervals.swap(last, i);
+        } else {
+            intervals[last] = intervals[last].merge_unchecked(&intervals[i]);
+        }
+    }
+
+    intervals.truncate(last + 1);
+}
+
+/// Normalizes the intervals into a set, and
+/// reports the pairs of input indices whose intervals overlapped and got merged.
+/// Each pair is ordered as `(smaller index, larger index)`, and the pairs are sorted.
+/// Intervals that merely touch, e.g., `(0, 1)` and `[1, 2]`, are merged but not reported.
+/// It takes O(n log n + k) time, where k is the number of reported pairs.
+pub fn normalize_with_report<T: PartialOrd + Clone>(
+    intervals: Vec<Interval<T>>
+) -> (IntervalSet<T>, Vec<(usize, usize)>) {
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by(|&i, &j| intervals[i].cmp_left_endpoints(&intervals[j]));
+
+    let mut merged: Vec<Interval<T>> = Vec::new();
+    let mut pairs = Vec::new();
+
+    // The indices of the inputs that may still overlap the upcoming intervals
+    let mut active: Vec<usize> = Vec::new();
+
+    for i in order {
+        let interval = &intervals[i];
+
+        // An active interval missing this one ends before it starts,
+        // and hence before every later interval starts as well,
+        // so each input leaves the active list at most once
+        active.retain(|&j| intervals[j].overlaps(interval));
+        pairs.extend(active.iter().map(|&j| (j.min(i), j.max(i))));
+        active.push(i);
+
+        match merged.last_mut() {
+            Some(last) if !last.is_separated_from(interval) => {
+                *last = last.merge_unchecked(interval);
+            }
+            _ => merged.push(interval.clone()),
+        }
+    }
+
+    pairs.sort();
+
+    (IntervalSet { intervals: merged }, pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce_intervals() {
+        let intervals: Vec<Interval<i32>> = vec![(0, 2), (1, 3), (3, 4), (6, 7), (8, 9), (9, 10)]
+            .into_iter()
+            .map(|(low, high)| Interval::closed_open(low, high).unwrap())
+            .coalesce_intervals()
+            .collect();
+
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::closed_open(0, 4).unwrap(),
+                Interval::closed_open(6, 7).unwrap(),
+                Interval::closed_open(8, 10).unwrap()
+            ]
+        );
+
+        let intervals: Vec<Interval<i32>> = vec![
+            Interval::unbounded_open(0),
+            Interval::open(0, 1).unwrap(),
+            Interval::closed_unbounded(1)
+        ]
+            .into_iter()
+            .coalesce_intervals()
+            .collect();
+
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::unbounded_open(0),
+                Interval::open_unbounded(0)
+            ]
+        );
+
+        let intervals: Vec<Interval<i32>> = Vec::new().into_iter().coalesce_intervals().collect();
+        assert!(intervals.is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_intervals_with() {
+        let intervals = vec![
+            Interval::<i32>::open(0, 1).unwrap(),
+            Interval::<i32>::closed(1, 2).unwrap(),
+            Interval::<i32>::closed(2, 3).unwrap()
+        ];
+
+        let coalesced: Vec<Interval<i32>> = intervals
+            .clone()
+            .into_iter()
+            .coalesce_intervals_with(MergePolicy::TopologicalClosure)
+            .collect();
+        assert_eq!(coalesced, vec![Interval::open_closed(0, 3).unwrap()]);
+
+        let coalesced: Vec<Interval<i32>> = intervals
+            .into_iter()
+            .coalesce_intervals_with(MergePolicy::Strict)
+            .collect();
+        assert_eq!(coalesced, vec![Interval::open(0, 1).unwrap(), Interval::closed(1, 3).unwrap()]);
+    }
+
+    #[test]
+    fn test_coalesce_sorted() {
+        let mut intervals = vec![
+            Interval::closed(0, 2).unwrap(),
+            Interval::open(1, 3).unwrap(),
+            Interval::closed(3, 4).unwrap(),
+            Interval::closed(6, 7).unwrap(),
+            Interval::closed(6, 9).unwrap(),
+            Interval::open_unbounded(12)
+        ];
+        coalesce_sorted(&mut intervals);
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::closed(0, 4).unwrap(),
+                Interval::closed(6, 9).unwrap(),
+                Interval::open_unbounded(12)
+            ]
+        );
+
+        // A disjoint list is unchanged
+        let disjoint = intervals.clone();
+        coalesce_sorted(&mut intervals);
+        assert_eq!(intervals, disjoint);
+
+        let mut intervals: Vec<Interval<i32>> = vec![];
+        coalesce_sorted(&mut intervals);
+        assert!(intervals.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_with_report() {
+        let intervals = vec![
+            Interval::closed(5, 8).unwrap(),
+            Interval::closed(0, 2).unwrap(),
+            Interval::closed(6, 10).unwrap()
+        ];
+        let (set, pairs) = normalize_with_report(intervals);
+
+        assert_eq!(
+            set.intervals,
+            vec![Interval::closed(0, 2).unwrap(), Interval::closed(5, 10).unwrap()]
+        );
+        assert_eq!(pairs, vec![(0, 2)]);
+
+        // Touching intervals are merged without being reported
+        let intervals = vec![Interval::closed_open(0, 1).unwrap(), Interval::closed(1, 2).unwrap()];
+        let (set, pairs) = normalize_with_report(intervals);
+        assert_eq!(set.intervals, vec![Interval::closed(0, 2).unwrap()]);
+        assert!(pairs.is_empty());
+
+        // A long interval overlaps every interval nested in it
+        let intervals = vec![
+            Interval::closed(0, 10).unwrap(),
+            Interval::closed(1, 2).unwrap(),
+            Interval::closed(3, 4).unwrap(),
+            Interval::closed(2, 3).unwrap()
+        ];
+        let (set, pairs) = normalize_with_report(intervals);
+        assert_eq!(set.intervals, vec![Interval::closed(0, 10).unwrap()]);
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 3), (2, 3)]);
+    }
+}
@@ -0,0 +1,70 @@
+use super::{ Interval, IntervalSet };
+
+/// Containers of disjoint intervals that behave like sets of points,
+/// so that algorithms can be written generically over them.
+pub trait IntervalContainer<T: PartialOrd + Clone> {
+    /// Checks if the value is in any of the intervals.
+    fn contains(&self, value: &T) -> bool;
+
+    /// Iterates over the intervals in ascending order.
+    fn iter_intervals<'a>(&'a self) -> impl Iterator<Item = &'a Interval<T>> where T: 'a;
+
+    /// Checks if the container has no intervals.
+    fn is_empty(&self) -> bool {
+        self.iter_intervals().next().is_none()
+    }
+}
+
+impl<T: PartialOrd + Clone> IntervalContainer<T> for IntervalSet<T> {
+    fn contains(&self, value: &T) -> bool {
+        IntervalSet::contains(self, value)
+    }
+
+    fn iter_intervals<'a>(&'a self) -> impl Iterator<Item = &'a Interval<T>> where T: 'a {
+        self.intervals.iter()
+    }
+
+    fn is_empty(&self) -> bool {
+        IntervalSet::is_empty(self)
+    }
+}
+
+impl<T: PartialOrd + Clone> IntervalContainer<T> for Interval<T> {
+    fn contains(&self, value: &T) -> bool {
+        Interval::contains(self, value)
+    }
+
+    fn iter_intervals<'a>(&'a self) -> impl Iterator<Item = &'a Interval<T>> where T: 'a {
+        std::iter::once(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the intervals in any container.
+    fn count_intervals<T: PartialOrd + Clone>(container: &impl IntervalContainer<T>) -> usize {
+        container.iter_intervals().count()
+    }
+
+    #[test]
+    fn test_interval_container() {
+        let set = IntervalSet::closed(0, 2)
+            .unwrap()
+            .union(&IntervalSet::open(5, 7).unwrap());
+        assert_eq!(count_intervals(&set), 2);
+        assert!(IntervalContainer::contains(&set, &6));
+        assert!(!IntervalContainer::contains(&set, &5));
+        assert!(!IntervalContainer::is_empty(&set));
+
+        let empty = set.intersection(&IntervalSet::closed(3, 4).unwrap());
+        assert_eq!(count_intervals(&empty), 0);
+        assert!(IntervalContainer::is_empty(&empty));
+
+        let interval = Interval::closed_open(0, 2).unwrap();
+        assert_eq!(count_intervals(&interval), 1);
+        assert!(IntervalContainer::contains(&interval, &0));
+        assert!(!IntervalContainer::is_empty(&interval));
+    }
+}
@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+use super::{ Endpoint, Interval, IntervalSet };
+
+/// Selects the fewest candidate intervals whose union covers the target interval,
+/// and returns their indices in the candidates.
+/// Returns `None` if the candidates cannot cover the target.
+pub fn min_intervals_to_cover<T: PartialOrd + Clone>(
+    target: &Interval<T>,
+    candidates: &[Interval<T>]
+) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = (0..candidates.len()).collect();
+    order.sort_by(|&i, &j| candidates[i].cmp_left_endpoints(&candidates[j]));
+
+    // The part of the target that is not covered yet
+    let mut remainder = target.clone();
+    let mut selected = Vec::new();
+    let mut k = 0;
+
+    loop {
+        // Among the candidates starting no later than the remainder,
+        // find the one reaching the farthest into the remainder
+        let mut best: Option<usize> = None;
+        while
+            k < order.len() &&
+            candidates[order[k]].cmp_left_endpoints(&remainder) != Ordering::Greater
+        {
+            let candidate = &candidates[order[k]];
+            let reaches_remainder = candidate.overlaps(&remainder);
+            let is_better = match best {
+                Some(best) =>
+                    candidate.cmp_right_endpoints(&candidates[best]) == Ordering::Greater,
+                None => true,
+            };
+            if reaches_remainder && is_better {
+                best = Some(order[k]);
+            }
+
+            k += 1;
+        }
+
+        let best = best?;
+        selected.push(best);
+
+        if candidates[best].cmp_right_endpoints(&remainder) != Ordering::Less {
+            return Some(selected);
+        }
+
+        // The remainder starts right after the selected candidate
+        let left = match candidates[best].right() {
+            Endpoint::Open(high) => Endpoint::Closed(high.clone()),
+            Endpoint::Closed(high) => Endpoint::Open(high.clone()),
+            Endpoint::Unbounded => {
+                return Some(selected);
+            }
+        };
+        remainder = Interval::new(left, remainder.right().clone()).ok()?;
+    }
+}
+
+/// Checks if the sets tile the window exactly,
+/// i.e., they are pairwise disjoint, and their union is the window.
+pub fn is_exact_partition<T: PartialOrd + Clone>(sets: &[IntervalSet<T>], window: &Interval<T>) -> bool {
+    for (i, set) in sets.iter().enumerate() {
+        if !sets[i + 1..].iter().all(|other| set.is_disjoint(other)) {
+            return false;
+        }
+    }
+
+    let union = IntervalSet::union_all(sets.iter().cloned());
+
+    union.intervals.len() == 1 && &union.intervals[0] == window
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_intervals_to_cover() {
+        let target = Interval::closed(0, 10).unwrap();
+        let candidates = vec![
+            Interval::closed(0, 3).unwrap(),
+            Interval::closed(2, 6).unwrap(),
+            Interval::closed(-1, 4).unwrap(),
+            Interval::closed(3, 5).unwrap(),
+            Interval::closed(4, 9).unwrap(),
+            Interval::open(8, 12).unwrap(),
+            Interval::closed(6, 10).unwrap()
+        ];
+
+        // [-1, 4] ∪ [4, 9] ∪ (8, 12) covers [0, 10]
+        assert_eq!(min_intervals_to_cover(&target, &candidates), Some(vec![2, 4, 5]));
+    }
+
+    #[test]
+    fn cover_is_impossible() {
+        let target = Interval::closed(0, 10).unwrap();
+
+        // The point 5 is missing
+        let candidates = vec![Interval::closed_open(0, 5).unwrap(), Interval::open(5, 10).unwrap()];
+        assert_eq!(min_intervals_to_cover(&target, &candidates), None);
+
+        // The point 0 is missing
+        let candidates = vec![Interval::open(0, 10).unwrap()];
+        assert_eq!(min_intervals_to_cover(&target, &candidates), None);
+
+        // The open endpoints meet a closed one
+        let candidates = vec![Interval::closed(0, 5).unwrap(), Interval::open_closed(5, 10).unwrap()];
+        assert_eq!(min_intervals_to_cover(&target, &candidates), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_is_exact_partition() {
+        let window = Interval::closed_open(0, 10).unwrap();
+        let sets = vec![
+            IntervalSet::closed_open(0, 3).unwrap().union(&IntervalSet::closed_open(7, 10).unwrap()),
+            IntervalSet::closed(3, 5).unwrap(),
+            IntervalSet::open(5, 6).unwrap().union(&IntervalSet::open(6, 7).unwrap()),
+            IntervalSet::closed(6, 6).unwrap()
+        ];
+        assert!(is_exact_partition(&sets, &window));
+
+        // The point 5 is double-booked
+        let mut overlapping = sets.clone();
+        overlapping[2] = IntervalSet::closed_open(5, 6).unwrap().union(&IntervalSet::open(6, 7).unwrap());
+        assert!(!is_exact_partition(&overlapping, &window));
+
+        // The point 6 is uncovered
+        assert!(!is_exact_partition(&sets[..3], &window));
+
+        // The sets reach outside the window
+        assert!(!is_exact_partition(&sets, &Interval::closed_open(0, 9).unwrap()));
+        assert!(!is_exact_partition(&[], &window));
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+use super::{ Endpoint, Interval, IntervalSet };
+
+/// Checks for each timestamp whether it falls inside the set.
+/// The timestamps must be sorted in ascending order,
+/// so that they are checked in a single O(n + m) merge walk
+/// instead of a binary search per timestamp.
+pub fn coverage_over_time<T: PartialOrd + Clone>(set: &IntervalSet<T>, timestamps: &[T]) -> Vec<bool> {
+    debug_assert!(
+        timestamps.windows(2).all(|pair| pair[0] <= pair[1]),
+        "timestamps must be sorted"
+    );
+
+    let mut i = 0;
+    timestamps
+        .iter()
+        .map(|timestamp| {
+            // Skip the intervals lying entirely before the timestamp,
+            // which also lie before all the later timestamps
+            while
+                i < set.intervals.len() &&
+                !set.intervals[i].contains(timestamp) &&
+                !set.intervals[i].is_after(timestamp)
+            {
+                i += 1;
+            }
+
+            set.intervals.get(i).is_some_and(|interval| interval.contains(timestamp))
+        })
+        .collect()
+}
+
+/// Gets the points covered by at least `k` of the sets,
+/// e.g., when at least 3 of 5 people are free.
+/// The boundaries of all the sets are swept in order while counting the overlap depth.
+pub fn covered_by_at_least<T: PartialOrd + Clone>(sets: &[IntervalSet<T>], k: usize) -> IntervalSet<T> {
+    if k == 0 {
+        return IntervalSet::from(Interval::universe());
+    }
+
+    // Each interval raises the depth at its left cut and lowers it at its right cut
+    let mut events: Vec<(Cut<T>, isize)> = sets
+        .iter()
+        .flat_map(|set| &set.intervals)
+        .flat_map(|interval| {
+            [(Cut::from_left(interval.left()), 1), (Cut::from_right(interval.right()), -1)]
+        })
+        .collect();
+    events.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut intervals = Vec::new();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < events.len() {
+        // Apply all the events at the same cut together
+        let cut = &events[i].0;
+        while i < events.len() && events[i].0.cmp(cut) == Ordering::Equal {
+            depth += events[i].1;
+            i += 1;
+        }
+
+        // The depth stays the same until the next cut
+        if depth >= (k as isize) {
+            if let Some((next, _)) = events.get(i) {
+                intervals.extend(Interval::new(cut.as_left(), next.as_right()).ok());
+            }
+        }
+    }
+
+    IntervalSet::from_intervals(intervals)
+}
+
+/// A cut of the line right before or right after a value, or at either infinity.
+enum Cut<T> {
+    NegativeInfinity,
+    Before(T),
+    After(T),
+    PositiveInfinity,
+}
+
+impl<T: PartialOrd + Clone> Cut<T> {
+    /// Gets the cut where an interval with the left endpoint starts.
+    fn from_left(endpoint: &Endpoint<T>) -> Self {
+        match endpoint {
+            Endpoint::Closed(value) => Cut::Before(value.clone()),
+            Endpoint::Open(value) => Cut::After(value.clone()),
+            Endpoint::Unbounded => Cut::NegativeInfinity,
+        }
+    }
+
+    /// Gets the cut where an interval with the right endpoint ends.
+    fn from_right(endpoint: &Endpoint<T>) -> Self {
+        match endpoint {
+            Endpoint::Closed(value) => Cut::After(value.clone()),
+            Endpoint::Open(value) => Cut::Before(value.clone()),
+            Endpoint::Unbounded => Cut::PositiveInfinity,
+        }
+    }
+
+    /// Gets the left endpoint of an interval starting at the cut.
+    fn as_left(&self) -> Endpoint<T> {
+        match self {
+            Cut::Before(value) => Endpoint::Closed(value.clone()),
+            Cut::After(value) => Endpoint::Open(value.clone()),
+            Cut::NegativeInfinity | Cut::PositiveInfinity => Endpoint::Unbounded,
+        }
+    }
+
+    /// Gets the right endpoint of an interval ending at the cut.
+    fn as_right(&self) -> Endpoint<T> {
+        match self {
+            Cut::Before(value) => Endpoint::Open(value.clone()),
+            Cut::After(value) => Endpoint::Closed(value.clone()),
+            Cut::NegativeInfinity | Cut::PositiveInfinity => Endpoint::Unbounded,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Cut::NegativeInfinity => 0,
+            Cut::Before(_) => 1,
+            Cut::After(_) => 2,
+            Cut::PositiveInfinity => 3,
+        }
+    }
+
+    /// Compares the cuts by their values first.
+    /// Incomparable values compare equal.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            | (Cut::Before(a) | Cut::After(a), Cut::Before(b) | Cut::After(b))
+                if a != b => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage_over_time() {
+        let mut set = IntervalSet::from(Interval::closed_open(0, 3).unwrap());
+        set = set.union(&IntervalSet::from(Interval::open_closed(5, 7).unwrap()));
+        set = set.union(&IntervalSet::from(Interval::closed_unbounded(10)));
+
+        let timestamps = [-1, 0, 2, 3, 3, 5, 6, 7, 8, 10, 42];
+        assert_eq!(
+            coverage_over_time(&set, &timestamps),
+            vec![false, true, true, false, false, false, true, true, false, true, true]
+        );
+
+        assert!(coverage_over_time(&set, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_covered_by_at_least() {
+        let sets = vec![
+            IntervalSet::closed(0, 10).unwrap(),
+            IntervalSet::closed_open(5, 15).unwrap().union(&IntervalSet::closed_unbounded(20)),
+            IntervalSet::open(8, 12).unwrap().union(&IntervalSet::closed(15, 25).unwrap())
+        ];
+
+        assert_eq!(
+            covered_by_at_least(&sets, 2).intervals,
+            vec![Interval::closed_open(5, 12).unwrap(), Interval::closed(20, 25).unwrap()]
+        );
+        assert_eq!(covered_by_at_least(&sets, 3).intervals, vec![Interval::open_closed(8, 10).unwrap()]);
+        assert_eq!(
+            covered_by_at_least(&sets, 1).intervals,
+            IntervalSet::union_all(sets.clone()).intervals
+        );
+        assert!(covered_by_at_least(&sets, 4).intervals.is_empty());
+        assert!(covered_by_at_least(&sets, 0).intervals[0].is_universe());
+    }
+
+    #[test]
+    fn touching_boundaries() {
+        // [0, 5] and [5, 9] share only the point 5
+        let sets = vec![IntervalSet::closed(0, 5).unwrap(), IntervalSet::closed(5, 9).unwrap()];
+        assert_eq!(covered_by_at_least(&sets, 2).intervals, vec![Interval::closed(5, 5).unwrap()]);
+
+        // [0, 5) and [5, 9] share nothing
+        let sets = vec![IntervalSet::closed_open(0, 5).unwrap(), IntervalSet::closed(5, 9).unwrap()];
+        assert!(covered_by_at_least(&sets, 2).intervals.is_empty());
+    }
+}
@@ -1,6 +1,174 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Endpoint<T: PartialOrd + Clone> {
     Open(T),
     Closed(T),
+    /// The endpoint at infinity.
+    /// It stands for the mathematical infinity regardless of the range of `T`,
+    /// rather than the maximum or minimum value of `T`.
     Unbounded,
 }
+
+impl<T: PartialOrd + Clone> Endpoint<T> {
+    /// Gets the value of the endpoint.
+    /// Returns `None` if the endpoint is unbounded.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Endpoint::Open(value) | Endpoint::Closed(value) => Some(value),
+            Endpoint::Unbounded => None,
+        }
+    }
+
+    /// Checks if the endpoint is unbounded.
+    pub fn is_unbounded(&self) -> bool {
+        matches!(self, Endpoint::Unbounded)
+    }
+
+    /// Checks if the endpoint is open.
+    /// An unbounded endpoint is not considered open.
+    pub fn is_open(&self) -> bool {
+        matches!(self, Endpoint::Open(_))
+    }
+
+    /// Checks if the endpoint is closed.
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Endpoint::Closed(_))
+    }
+
+    /// Compares the endpoints as the left endpoints of intervals.
+    /// An unbounded endpoint is the smallest, and
+    /// a closed endpoint is smaller than an open one with the same value,
+    /// e.g., `[0` < `(0` < `[1`.
+    /// Incomparable values are treated as equal.
+    pub fn cmp_as_left(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Endpoint::Unbounded, Endpoint::Unbounded) => Ordering::Equal,
+            (Endpoint::Unbounded, _) => Ordering::Less,
+            (_, Endpoint::Unbounded) => Ordering::Greater,
+            (
+                Endpoint::Open(this) | Endpoint::Closed(this),
+                Endpoint::Open(other_value) | Endpoint::Closed(other_value),
+            ) =>
+                this
+                    .partial_cmp(other_value)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| match (self, other) {
+                        (Endpoint::Closed(_), Endpoint::Open(_)) => Ordering::Less,
+                        (Endpoint::Open(_), Endpoint::Closed(_)) => Ordering::Greater,
+                        _ => Ordering::Equal,
+                    }),
+        }
+    }
+
+    /// Compares the endpoints as the right endpoints of intervals.
+    /// An unbounded endpoint is the greatest, and
+    /// an open endpoint is smaller than a closed one with the same value,
+    /// e.g., `0)` < `0]` < `1)`.
+    /// Incomparable values are treated as equal.
+    pub fn cmp_as_right(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Endpoint::Unbounded, Endpoint::Unbounded) => Ordering::Equal,
+            (Endpoint::Unbounded, _) => Ordering::Greater,
+            (_, Endpoint::Unbounded) => Ordering::Less,
+            (
+                Endpoint::Open(this) | Endpoint::Closed(this),
+                Endpoint::Open(other_value) | Endpoint::Closed(other_value),
+            ) =>
+                this
+                    .partial_cmp(other_value)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| match (self, other) {
+                        (Endpoint::Open(_), Endpoint::Closed(_)) => Ordering::Less,
+                        (Endpoint::Closed(_), Endpoint::Open(_)) => Ordering::Greater,
+                        _ => Ordering::Equal,
+                    }),
+        }
+    }
+}
+
+/// Formats the endpoint on its own, without knowing which side of an interval it is on,
+/// e.g., `open 0`, `closed 1` and `unbounded`.
+impl<T: PartialOrd + Clone + Display> Display for Endpoint<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Endpoint::Open(value) => write!(f, "open {}", value),
+            Endpoint::Closed(value) => write!(f, "closed {}", value),
+            Endpoint::Unbounded => write!(f, "unbounded"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accessors() {
+        let open = Endpoint::Open(0);
+        assert_eq!(open.value(), Some(&0));
+        assert!(open.is_open());
+        assert!(!open.is_closed());
+        assert!(!open.is_unbounded());
+
+        let closed = Endpoint::Closed(1);
+        assert_eq!(closed.value(), Some(&1));
+        assert!(!closed.is_open());
+        assert!(closed.is_closed());
+        assert!(!closed.is_unbounded());
+
+        let unbounded = Endpoint::<i32>::Unbounded;
+        assert_eq!(unbounded.value(), None);
+        assert!(!unbounded.is_open());
+        assert!(!unbounded.is_closed());
+        assert!(unbounded.is_unbounded());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Endpoint::Open(0).to_string(), "open 0");
+        assert_eq!(Endpoint::Closed(-1.5).to_string(), "closed -1.5");
+        assert_eq!(Endpoint::<i32>::Unbounded.to_string(), "unbounded");
+    }
+
+    #[test]
+    fn test_cmp_as_left() {
+        let endpoints = [
+            Endpoint::Unbounded,
+            Endpoint::Closed(0),
+            Endpoint::Open(0),
+            Endpoint::Closed(1),
+            Endpoint::Open(1),
+        ];
+
+        // The endpoints are listed in ascending order
+        for (i, a) in endpoints.iter().enumerate() {
+            for (j, b) in endpoints.iter().enumerate() {
+                assert_eq!(a.cmp_as_left(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+
+        assert_eq!(Endpoint::Open(f64::NAN).cmp_as_left(&Endpoint::Open(0.0)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_as_right() {
+        let endpoints = [
+            Endpoint::Open(0),
+            Endpoint::Closed(0),
+            Endpoint::Open(1),
+            Endpoint::Closed(1),
+            Endpoint::Unbounded,
+        ];
+
+        // The endpoints are listed in ascending order
+        for (i, a) in endpoints.iter().enumerate() {
+            for (j, b) in endpoints.iter().enumerate() {
+                assert_eq!(a.cmp_as_right(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+
+        assert_eq!(Endpoint::Closed(f64::NAN).cmp_as_right(&Endpoint::Closed(0.0)), Ordering::Equal);
+    }
+}
@@ -2,11 +2,55 @@ use thiserror::Error;
 
 pub type IntervalSetResult<T> = std::result::Result<T, IntervalSetError>;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IntervalSetError {
     #[error("invalid interval")]
     InvalidInterval,
 
-    #[error("separated intervals cannot be merged")]
-    MergeSeparatedIntervals,
+    #[error("endpoint value is not comparable with itself, e.g., NaN")]
+    NonComparableEndpoint,
+
+    #[error("comparator disagrees with the natural order of the endpoint values")]
+    InconsistentComparator,
+
+    #[error("interval is unbounded")]
+    UnboundedInterval,
+
+    #[error("interval set has {components} connected components instead of one")]
+    NotConnected {
+        components: usize,
+    },
+
+    #[error("interval at index {index} is invalid")]
+    InvalidIntervalAt {
+        index: usize,
+    },
+
+    #[error(
+        "interval at index {index} is not sorted before or separated from the next interval"
+    )]
+    UnnormalizedIntervalAt {
+        index: usize,
+    },
+
+    #[error("expected {expected} weights, one per interval, but got {actual}")]
+    WeightCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("grid cell boundary overflows the value type")]
+    GridCellOverflow,
+
+    #[error("invalid bytes of an interval set")]
+    InvalidBytes,
+
+    #[error("cannot parse {0:?} as an interval or interval set")]
+    ParseError(String),
+
+    #[error("cannot merge separated intervals {left} and {right}")]
+    MergeSeparatedIntervals {
+        left: String,
+        right: String,
+    },
 }
@@ -1,4 +1,6 @@
-use std::fmt::Display;
+use std::cmp::Ordering;
+use std::fmt::{ Debug, Display, Write };
+use std::ops::{ Add, Shl, Shr, Sub };
 use super::{ Endpoint, IntervalSetResult, IntervalSetError };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -12,7 +14,12 @@ pub struct Interval<T: PartialOrd + Clone> {
 
 impl<T: PartialOrd + Clone> Interval<T> {
     /// Creates a new interval by specifying the left and right endpoints.
+    /// Endpoint values that cannot be compared with themselves, e.g., `f64::NAN`,
+    /// are rejected with [`IntervalSetError::NonComparableEndpoint`].
     pub fn new(left: Endpoint<T>, right: Endpoint<T>) -> IntervalSetResult<Self> {
+        check_endpoint(&left)?;
+        check_endpoint(&right)?;
+
         match (&left, &right) {
             | (Endpoint::Open(low), Endpoint::Open(high))
             | (Endpoint::Open(low), Endpoint::Closed(high))
@@ -56,8 +63,54 @@ impl<T: PartialOrd + Clone> Interval<T> {
         }
     }
 
+    /// Creates a new interval by specifying the left and right endpoints,
+    /// checking their order with the comparator, e.g., `f64::total_cmp`.
+    /// The comparator does not define a new order, since intervals are always ordered by `PartialOrd`.
+    /// It only double-checks the endpoints, and must agree with `PartialOrd` on their values,
+    /// or else [`IntervalSetError::InconsistentComparator`] is returned,
+    /// so a reversed order is rejected rather than supported.
+    /// Endpoint values that cannot be compared with themselves, e.g., `f64::NAN`,
+    /// are rejected with [`IntervalSetError::NonComparableEndpoint`] before the comparator sees them.
+    pub fn new_by(
+        left: Endpoint<T>,
+        right: Endpoint<T>,
+        cmp: impl Fn(&T, &T) -> Ordering
+    ) -> IntervalSetResult<Self> {
+        // Reject values like NaN
+        check_endpoint(&left)?;
+        check_endpoint(&right)?;
+
+        let (Some(low), Some(high)) = (left.value(), right.value()) else {
+            // Either endpoint is unbounded
+            return Ok(Self { left, right });
+        };
+
+        let ordering = cmp(low, high);
+        if low.partial_cmp(high) != Some(ordering) {
+            return Err(IntervalSetError::InconsistentComparator);
+        }
+
+        let is_valid = match (&left, &right) {
+            // Closed interval may be degenerate
+            (Endpoint::Closed(_), Endpoint::Closed(_)) => ordering != Ordering::Greater,
+            _ => ordering == Ordering::Less,
+        };
+
+        if is_valid {
+            Ok(Self {
+                left,
+                right,
+            })
+        } else {
+            Err(IntervalSetError::InvalidInterval)
+        }
+    }
+
     /// Creates an open interval.
     pub fn open(low: T, high: T) -> IntervalSetResult<Self> {
+        check_value(&low)?;
+        check_value(&high)?;
+
         // Left value must be strictly less than right value
         if low < high {
             Ok(Self {
@@ -72,6 +125,9 @@ impl<T: PartialOrd + Clone> Interval<T> {
     /// Creates a closed interval.
     /// The interval may be degenerate.
     pub fn closed(low: T, high: T) -> IntervalSetResult<Self> {
+        check_value(&low)?;
+        check_value(&high)?;
+
         // Left value must be less than or equal to right value
         if low <= high {
             Ok(Self {
@@ -83,8 +139,29 @@ impl<T: PartialOrd + Clone> Interval<T> {
         }
     }
 
+    /// Creates a closed interval from two values given in either order.
+    /// Unlike [`Interval::closed`], this never fails on reversed input.
+    /// The interval is degenerate if the two values are equal.
+    ///
+    /// # Panics
+    /// Panics if either value is not comparable with itself, e.g., `f64::NAN`.
+    pub fn ordered_closed(a: T, b: T) -> Self {
+        assert_comparable(&a);
+        assert_comparable(&b);
+
+        let (low, high) = if a > b { (b, a) } else { (a, b) };
+
+        Self {
+            left: Endpoint::Closed(low),
+            right: Endpoint::Closed(high),
+        }
+    }
+
     /// Creates a left-open right-closed interval.
     pub fn open_closed(low: T, high: T) -> IntervalSetResult<Self> {
+        check_value(&low)?;
+        check_value(&high)?;
+
         // Left value must be less than right value
         if low < high {
             Ok(Self {
@@ -98,6 +175,9 @@ impl<T: PartialOrd + Clone> Interval<T> {
 
     /// Creates a left-closed right-open interval.
     pub fn closed_open(low: T, high: T) -> IntervalSetResult<Self> {
+        check_value(&low)?;
+        check_value(&high)?;
+
         // Left value must be less than right value
         if low < high {
             Ok(Self {
@@ -110,7 +190,12 @@ impl<T: PartialOrd + Clone> Interval<T> {
     }
 
     /// Creates an interval that is unbounded on the left and open on the right.
+    ///
+    /// # Panics
+    /// Panics if the value is not comparable with itself, e.g., `f64::NAN`.
     pub fn unbounded_open(high: T) -> Self {
+        assert_comparable(&high);
+
         Self {
             left: Endpoint::Unbounded,
             right: Endpoint::Open(high),
@@ -118,7 +203,12 @@ impl<T: PartialOrd + Clone> Interval<T> {
     }
 
     /// Creates an interval that is unbounded on the left and closed on the right.
+    ///
+    /// # Panics
+    /// Panics if the value is not comparable with itself, e.g., `f64::NAN`.
     pub fn unbounded_closed(high: T) -> Self {
+        assert_comparable(&high);
+
         Self {
             left: Endpoint::Unbounded,
             right: Endpoint::Closed(high),
@@ -126,7 +216,12 @@ impl<T: PartialOrd + Clone> Interval<T> {
     }
 
     /// Creates an interval that is open on the left and unbounded on the right.
+    ///
+    /// # Panics
+    /// Panics if the value is not comparable with itself, e.g., `f64::NAN`.
     pub fn open_unbounded(low: T) -> Self {
+        assert_comparable(&low);
+
         Self {
             left: Endpoint::Open(low),
             right: Endpoint::Unbounded,
@@ -134,13 +229,36 @@ impl<T: PartialOrd + Clone> Interval<T> {
     }
 
     /// Creates an interval that is closed on the left and unbounded on the right.
+    ///
+    /// # Panics
+    /// Panics if the value is not comparable with itself, e.g., `f64::NAN`.
     pub fn closed_unbounded(low: T) -> Self {
+        assert_comparable(&low);
+
         Self {
             left: Endpoint::Closed(low),
             right: Endpoint::Unbounded,
         }
     }
 
+    /// Creates the interval of the values at least `low`, i.e., `[low, +∞)`.
+    /// This is the same as [`Interval::closed_unbounded`].
+    pub fn at_least(low: T) -> Self {
+        Self::closed_unbounded(low)
+    }
+
+    /// Creates the interval of the values at most `high`, i.e., `(-∞, high]`.
+    /// This is the same as [`Interval::unbounded_closed`].
+    pub fn at_most(high: T) -> Self {
+        Self::unbounded_closed(high)
+    }
+
+    /// Creates the interval of the values up to but excluding `high`, i.e., `(-∞, high)`.
+    /// This is the same as [`Interval::unbounded_open`].
+    pub fn up_to(high: T) -> Self {
+        Self::unbounded_open(high)
+    }
+
     /// Creates a universal interval.
     pub fn universe() -> Self {
         Self {
@@ -202,7 +320,82 @@ impl<T: PartialOrd + Clone> Interval<T> {
 
     /// Checks if the interval is unbounded.
     pub fn is_unbounded(&self) -> bool {
-        return !self.is_bounded();
+        !self.is_bounded()
+    }
+
+    /// Checks if the interval contains the value.
+    pub fn contains(&self, value: &T) -> bool {
+        let is_above_left = match &self.left {
+            Endpoint::Open(low) => value > low,
+            Endpoint::Closed(low) => value >= low,
+            Endpoint::Unbounded => true,
+        };
+
+        let is_below_right = match &self.right {
+            Endpoint::Open(high) => value < high,
+            Endpoint::Closed(high) => value <= high,
+            Endpoint::Unbounded => true,
+        };
+
+        is_above_left && is_below_right
+    }
+
+    /// Checks if every value lies in the interval,
+    /// stopping at the first value outside.
+    pub fn contains_all(&self, values: &[T]) -> bool {
+        values.iter().all(|value| self.contains(value))
+    }
+
+    /// Clamps the value into the interval,
+    /// i.e., returns the value if it is in the interval, or the nearest boundary value otherwise,
+    /// e.g., clamping 15 into `[0, 10]` gives 10.
+    /// The boundary value is returned even if the endpoint is open,
+    /// since no value of an open interval is the nearest to the outside,
+    /// so clamping 15 into `(0, 10)` gives 10 as well.
+    /// Values on an unbounded side, and values not comparable to the endpoints, are unchanged.
+    pub fn clamp(&self, value: T) -> T {
+        if let Some(low) = self.low() {
+            if value < low {
+                return low;
+            }
+        }
+
+        if let Some(high) = self.high() {
+            if value > high {
+                return high;
+            }
+        }
+
+        value
+    }
+
+    /// Splits the interval at the value into two disjoint pieces whose union is the interval,
+    /// where the cut is open on the left piece and closed on the right piece,
+    /// e.g., splitting `[0, 10]` at 5 gives `[0, 5)` and `[5, 10]`.
+    /// Returns `None` if the value is not strictly inside the interval,
+    /// i.e., it lies outside or on an endpoint.
+    pub fn split_at(&self, value: T) -> Option<(Self, Self)> {
+        let is_on = |endpoint: &Endpoint<T>| {
+            matches!(endpoint, Endpoint::Open(bound) | Endpoint::Closed(bound) if bound == &value)
+        };
+        if !self.contains(&value) || is_on(&self.left) || is_on(&self.right) {
+            return None;
+        }
+
+        let left = Self { left: self.left.clone(), right: Endpoint::Open(value.clone()) };
+        let right = Self { left: Endpoint::Closed(value), right: self.right.clone() };
+
+        Some((left, right))
+    }
+
+    /// Checks if the whole interval lies to the right of the value,
+    /// i.e., the value is excluded by the left endpoint.
+    pub(crate) fn is_after(&self, value: &T) -> bool {
+        match &self.left {
+            Endpoint::Open(low) => value <= low,
+            Endpoint::Closed(low) => value < low,
+            Endpoint::Unbounded => false,
+        }
     }
 
     /// Checks if the interval is separated from the other interval.
@@ -214,18 +407,78 @@ impl<T: PartialOrd + Clone> Interval<T> {
             self.is_other_separated_from_this_to_the_right(other)
     }
 
-    /// Merges two intervals.
-    /// Returns an error if the two intervals are separated.
-    pub fn merge(&self, other: &Self) -> IntervalSetResult<Self> {
+    /// Checks if the two intervals share at least one common point,
+    /// e.g., `[0, 1]` and `[1, 2]` overlap at `1`, but `[0, 1)` and `[1, 2]` do not.
+    /// This is not the negation of [`Interval::is_separated_from`],
+    /// since touching intervals such as `[0, 1)` and `[1, 2]` are neither separated nor overlapping.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Checks if the interval touches the other interval.
+    /// Two intervals touch if and only if they are adjacent, i.e.,
+    /// they are not separated, and they meet at a single boundary value
+    /// without overlapping in their interiors.
+    /// The value must be the right boundary of one interval and the left boundary of the other.
+    /// For example, `[0, 1]` touches `[1, 2]`, and `[0, 1)` touches `[1, 2]`,
+    /// but `[0, 2]` does not touch `[1, 3]`, `(0, 1)` does not touch `(1, 2)`,
+    /// and `[1, 1]` does not touch `[0, 2]` since it lies strictly inside.
+    pub fn touches(&self, other: &Self) -> bool {
         if self.is_separated_from(other) {
-            // Return an error since they are separated, and hence cannot be merged
-            Err(IntervalSetError::MergeSeparatedIntervals)
+            return false;
+        }
+
+        // The right boundary of one interval must meet the left boundary of the other
+        let meets = |a: &Self, b: &Self| match (a.right.value(), b.left.value()) {
+            (Some(high), Some(low)) => high == low,
+            _ => false,
+        };
+
+        meets(self, other) || meets(other, self)
+    }
+
+    /// Gets the gap between the two intervals,
+    /// i.e., the interval of the points lying strictly between them.
+    /// The endpoints of the gap have the opposite openness of the neighboring endpoints,
+    /// e.g., the gap between `[0, 1]` and `[3, 4]` is `(1, 3)`, and
+    /// the gap between `(0, 1)` and `(1, 2)` is `[1]`.
+    /// Returns `None` if the two intervals are not separated,
+    /// i.e., they touch or overlap.
+    pub fn gap_to(&self, other: &Self) -> Option<Self> {
+        if !self.is_separated_from(other) {
+            return None;
+        }
+
+        let (former, latter) = if self.cmp_left_endpoints(other) == Ordering::Greater {
+            (other, self)
         } else {
-            // Merge the two intervals into a new interval
-            Ok(Self {
-                left: self.smaller_left_endpoint(other),
-                right: self.greater_right_endpoint(other),
-            })
+            (self, other)
+        };
+
+        let left = match &former.right {
+            Endpoint::Open(high) => Endpoint::Closed(high.clone()),
+            Endpoint::Closed(high) => Endpoint::Open(high.clone()),
+            Endpoint::Unbounded => {
+                return None;
+            }
+        };
+        let right = match &latter.left {
+            Endpoint::Open(low) => Endpoint::Closed(low.clone()),
+            Endpoint::Closed(low) => Endpoint::Open(low.clone()),
+            Endpoint::Unbounded => {
+                return None;
+            }
+        };
+
+        Self::new(left, right).ok()
+    }
+
+    /// Merges two intervals without checking whether they are separated.
+    /// The caller must make sure that the two intervals are not separated.
+    pub(crate) fn merge_unchecked(&self, other: &Self) -> Self {
+        Self {
+            left: self.smaller_left_endpoint(other),
+            right: self.greater_right_endpoint(other),
         }
     }
 
@@ -273,165 +526,312 @@ impl<T: PartialOrd + Clone> Interval<T> {
         }
     }
 
-    /// Gets the smaller left endpoint of the two intervals.
-    fn smaller_left_endpoint(&self, other: &Self) -> Endpoint<T> {
-        // If either interval is unbounded on the left, return unbounded
-        if matches!(&self.left, Endpoint::Unbounded) || matches!(&other.left, Endpoint::Unbounded) {
-            return Endpoint::Unbounded;
-        }
+    /// Compares the left endpoints of the two intervals.
+    /// See [`Endpoint::cmp_as_left`] for the order.
+    pub(crate) fn cmp_left_endpoints(&self, other: &Self) -> Ordering {
+        self.left.cmp_as_left(&other.left)
+    }
 
-        // Get the low values of the two intervals
-        // It is safe to unwrap because we already checked that the intervals are bounded
-        let this_low = self.low().unwrap();
-        let other_low = other.low().unwrap();
+    /// Compares the right endpoints of the two intervals.
+    /// See [`Endpoint::cmp_as_right`] for the order.
+    pub(crate) fn cmp_right_endpoints(&self, other: &Self) -> Ordering {
+        self.right.cmp_as_right(&other.right)
+    }
 
-        if this_low < other_low {
-            self.left.clone()
-        } else if this_low > other_low {
-            other.left.clone()
-        } else {
-            // The low values are equal
-
-            // If either interval is closed on the left, return the closed endpoint
-            if
-                matches!(&self.left, Endpoint::Closed(_)) ||
-                matches!(&other.left, Endpoint::Closed(_))
-            {
-                Endpoint::Closed(this_low)
-            } else {
-                // Else, return the open endpoint
-                Endpoint::Open(this_low)
-            }
-        }
+    /// Gets the intersection of the two intervals,
+    /// e.g., `[0, 5] ∩ [3, 8] = [3, 5]` and `[0, 2] ∩ [2, 4] = [2]`.
+    /// If they share no common point, e.g., `(0, 1)` and `(1, 2)`, returns `None`.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.greater_left_endpoint(other), self.less_right_endpoint(other)).ok()
     }
 
-    /// Gets the greater left endpoint of the two intervals.
-    fn greater_left_endpoint(&self, other: &Self) -> Endpoint<T> {
-        // If this interval is unbounded on the left, return the left endpoint of other interval
-        if matches!(&self.left, Endpoint::Unbounded) {
-            return other.left.clone();
-        }
+    /// Converts the endpoint values to another type, e.g., from `i64` to `i32`.
+    /// Unbounded sides stay unbounded.
+    /// Returns `None` if any conversion fails.
+    pub fn try_cast<U: PartialOrd + Clone + TryFrom<T>>(&self) -> Option<Interval<U>> {
+        let cast = |endpoint: &Endpoint<T>| -> Option<Endpoint<U>> {
+            Some(match endpoint {
+                Endpoint::Open(value) => Endpoint::Open(U::try_from(value.clone()).ok()?),
+                Endpoint::Closed(value) => Endpoint::Closed(U::try_from(value.clone()).ok()?),
+                Endpoint::Unbounded => Endpoint::Unbounded,
+            })
+        };
 
-        // If other interval is unbounded on the left, return the left endpoint of this interval
-        if matches!(&other.left, Endpoint::Unbounded) {
-            return self.left.clone();
-        }
+        Interval::new(cast(&self.left)?, cast(&self.right)?).ok()
+    }
 
-        // Get the low values of the two intervals
-        // It is safe to unwrap because we already checked that the intervals are bounded
-        let this_low = self.low().unwrap();
-        let other_low = other.low().unwrap();
+    /// Creates a new interval by applying `f` to the values of both endpoints.
+    /// If `reverse` is `true`, the endpoints swap sides,
+    /// which is required when `f` is decreasing.
+    ///
+    /// # Panics
+    /// Panics if a mapped value is not comparable with itself, e.g., `f64::NAN`.
+    pub(crate) fn map_endpoints(&self, f: impl Fn(T) -> T, reverse: bool) -> Self {
+        let map = |endpoint: &Endpoint<T>| {
+            match endpoint {
+                Endpoint::Open(value) => Endpoint::Open(f(value.clone())),
+                Endpoint::Closed(value) => Endpoint::Closed(f(value.clone())),
+                Endpoint::Unbounded => Endpoint::Unbounded,
+            }
+        };
 
-        if this_low > other_low {
-            self.left.clone()
-        } else if this_low < other_low {
-            other.left.clone()
+        let interval = if reverse {
+            Self {
+                left: map(&self.right),
+                right: map(&self.left),
+            }
         } else {
-            // The low values are equal
-
-            // If either interval is open on the left, return the open endpoint
-            if matches!(&self.left, Endpoint::Open(_)) || matches!(&other.left, Endpoint::Open(_)) {
-                Endpoint::Open(this_low)
-            } else {
-                // Else, return the closed endpoint
-                Endpoint::Closed(this_low)
+            Self {
+                left: map(&self.left),
+                right: map(&self.right),
             }
+        };
+
+        // Reject values like NaN, e.g., from adding infinities of opposite signs
+        interval.left.value().into_iter().chain(interval.right.value()).for_each(assert_comparable);
+
+        interval
+    }
+
+    /// Gets the smaller left endpoint of the two intervals.
+    fn smaller_left_endpoint(&self, other: &Self) -> Endpoint<T> {
+        match self.cmp_left_endpoints(other) {
+            Ordering::Greater => other.left.clone(),
+            _ => self.left.clone(),
+        }
+    }
+
+    /// Gets the greater left endpoint of the two intervals.
+    fn greater_left_endpoint(&self, other: &Self) -> Endpoint<T> {
+        match self.cmp_left_endpoints(other) {
+            Ordering::Less => other.left.clone(),
+            _ => self.left.clone(),
         }
     }
 
     /// Gets the less right endpoint of the two intervals.
     fn less_right_endpoint(&self, other: &Self) -> Endpoint<T> {
-        // If this interval is unbounded on the right, return the right endpoint of other interval
-        if matches!(&self.right, Endpoint::Unbounded) {
-            return other.right.clone();
+        match self.cmp_right_endpoints(other) {
+            Ordering::Greater => other.right.clone(),
+            _ => self.right.clone(),
         }
+    }
 
-        // If other interval is unbounded on the right, return the right endpoint of this interval
-        if matches!(&other.right, Endpoint::Unbounded) {
-            return self.right.clone();
+    /// Gets the greater right endpoint of the two intervals.
+    fn greater_right_endpoint(&self, other: &Self) -> Endpoint<T> {
+        match self.cmp_right_endpoints(other) {
+            Ordering::Less => other.right.clone(),
+            _ => self.right.clone(),
         }
+    }
 
-        // Get the high values of the two intervals
-        // It is safe to unwrap because we already checked that the intervals are bounded
-        let this_high = self.high().unwrap();
-        let other_high = other.high().unwrap();
-
-        if this_high < other_high {
-            self.right.clone()
-        } else if this_high > other_high {
-            other.right.clone()
-        } else {
-            // The high values are equal
-
-            // If either interval is open on the right, return the open endpoint
-            if
-                matches!(&self.right, Endpoint::Open(_)) ||
-                matches!(&other.right, Endpoint::Open(_))
-            {
-                Endpoint::Open(this_high)
+    /// Writes the interval with each endpoint value rendered by `format`.
+    fn write_with(&self, f: &mut impl Write, format: impl Fn(&T) -> String) -> std::fmt::Result {
+        match (&self.left, &self.right) {
+            (Endpoint::Open(low), Endpoint::Open(high)) => {
+                write!(f, "({}, {})", format(low), format(high))
+            }
+            (Endpoint::Open(low), Endpoint::Closed(high)) => {
+                write!(f, "({}, {}]", format(low), format(high))
+            }
+            (Endpoint::Closed(low), Endpoint::Open(high)) => {
+                write!(f, "[{}, {})", format(low), format(high))
+            }
+            (Endpoint::Closed(low), Endpoint::Closed(high)) => if low == high {
+                write!(f, "[{}]", format(low))
             } else {
-                // Else, return the closed endpoint
-                Endpoint::Closed(this_high)
+                write!(f, "[{}, {}]", format(low), format(high))
             }
+            (Endpoint::Unbounded, Endpoint::Open(high)) => { write!(f, "(-∞, {})", format(high)) }
+            (Endpoint::Unbounded, Endpoint::Closed(high)) => { write!(f, "(-∞, {}]", format(high)) }
+            (Endpoint::Open(low), Endpoint::Unbounded) => { write!(f, "({}, +∞)", format(low)) }
+            (Endpoint::Closed(low), Endpoint::Unbounded) => { write!(f, "[{}, +∞)", format(low)) }
+            (Endpoint::Unbounded, Endpoint::Unbounded) => { write!(f, "(-∞, +∞)") }
         }
     }
+}
 
-    /// Gets the greater right endpoint of the two intervals.
-    fn greater_right_endpoint(&self, other: &Self) -> Endpoint<T> {
-        // If either interval is unbounded on the right, return unbounded
-        if
-            matches!(&self.right, Endpoint::Unbounded) ||
-            matches!(&other.right, Endpoint::Unbounded)
-        {
-            return Endpoint::Unbounded;
-        }
-
-        // Get the high values of the two intervals
-        // It is safe to unwrap because we already checked that the intervals are bounded
-        let this_high = self.high().unwrap();
-        let other_high = other.high().unwrap();
-
-        if this_high > other_high {
-            self.right.clone()
-        } else if this_high < other_high {
-            other.right.clone()
+impl<T: PartialOrd + Clone + Debug> Interval<T> {
+    /// Merges two intervals.
+    /// Returns an error if the two intervals are separated,
+    /// which describes them with the debug format of their endpoint values,
+    /// so that `T` need not be displayable.
+    pub fn merge(&self, other: &Self) -> IntervalSetResult<Self> {
+        if self.is_separated_from(other) {
+            // Return an error since they are separated, and hence cannot be merged
+            Err(IntervalSetError::MergeSeparatedIntervals {
+                left: self.to_debug_string(),
+                right: other.to_debug_string(),
+            })
         } else {
-            // The high values are equal
-
-            // If either interval is closed on the right, return the closed endpoint
-            if
-                matches!(&self.right, Endpoint::Closed(_)) ||
-                matches!(&other.right, Endpoint::Closed(_))
-            {
-                Endpoint::Closed(this_high)
-            } else {
-                // Else, return the open endpoint
-                Endpoint::Open(this_high)
-            }
+            // Merge the two intervals into a new interval
+            Ok(self.merge_unchecked(other))
         }
     }
+
+    /// Formats the interval in the same notation as `Display`,
+    /// but with the debug format of the endpoint values.
+    fn to_debug_string(&self) -> String {
+        let mut output = String::new();
+        // Writing to a string never fails
+        let _ = self.write_with(&mut output, |value| format!("{:?}", value));
+
+        output
+    }
+}
+
+impl<T: PartialOrd + Clone + Add<Output = T>> Interval<T> {
+    /// Translates the interval by adding the offset to both endpoints.
+    /// Unbounded sides stay unbounded.
+    ///
+    /// # Panics
+    /// Panics if an endpoint value becomes not comparable with itself,
+    /// e.g., translating by `f64::NAN`.
+    pub fn translate(&self, offset: T) -> Self {
+        self.map_endpoints(|value| value + offset.clone(), false)
+    }
+}
+
+impl<T: PartialOrd + Clone + Sub<Output = T>> Interval<T> {
+    /// Gets the width of the interval, i.e., the high value minus the low value,
+    /// regardless of whether the endpoints are open or closed.
+    /// If the interval is unbounded, returns `None`.
+    pub fn width(&self) -> Option<T> {
+        Some(self.high()? - self.low()?)
+    }
+
+    /// Gets the measure, i.e., the length of the interval,
+    /// which is the same as its width.
+    /// A degenerate interval such as `[2, 2]` has measure zero.
+    /// If the interval is unbounded, returns `None`.
+    pub fn measure(&self) -> Option<T> {
+        self.width()
+    }
+}
+
+impl<T: PartialOrd + Clone + Add<Output = T> + Sub<Output = T>> Interval<T> {
+    /// Reflects the interval around the center,
+    /// i.e., maps each point `x` to `2 * center - x`.
+    /// The endpoints swap sides while keeping their kinds,
+    /// e.g., reflecting `[1, 4)` around `0` gives `(-4, -1]`.
+    ///
+    /// # Panics
+    /// Panics if an endpoint value becomes not comparable with itself,
+    /// e.g., reflecting around `f64::NAN`.
+    pub fn reflect(&self, center: T) -> Self {
+        self.map_endpoints(|value| center.clone() + center.clone() - value, true)
+    }
+
+    /// Dilates the interval by moving the left endpoint to the left and
+    /// the right endpoint to the right by the margin.
+    /// A negative margin erodes the interval instead, e.g., dilating `[0, 10]` by -2 gives `[2, 8]`.
+    /// Returns `None` if the eroded interval is empty, e.g., dilating `[0, 1]` by -1,
+    /// or if an endpoint value becomes not comparable with itself, e.g., dilating by `f64::NAN`.
+    pub fn dilate(&self, margin: T) -> Option<Self> {
+        let left = match &self.left {
+            Endpoint::Open(low) => Endpoint::Open(low.clone() - margin.clone()),
+            Endpoint::Closed(low) => Endpoint::Closed(low.clone() - margin.clone()),
+            Endpoint::Unbounded => Endpoint::Unbounded,
+        };
+        let right = match &self.right {
+            Endpoint::Open(high) => Endpoint::Open(high.clone() + margin),
+            Endpoint::Closed(high) => Endpoint::Closed(high.clone() + margin),
+            Endpoint::Unbounded => Endpoint::Unbounded,
+        };
+
+        Self::new(left, right).ok()
+    }
+}
+
+/// Shifts the interval to the right, i.e., translates it by adding the offset.
+/// This is not a bitwise operation.
+/// Panics as [`Interval::translate`] does.
+impl<T: PartialOrd + Clone + Add<Output = T>> Shr<T> for Interval<T> {
+    type Output = Self;
+
+    fn shr(self, offset: T) -> Self::Output {
+        self.translate(offset)
+    }
+}
+
+/// Shifts the interval to the left, i.e., translates it by subtracting the offset.
+/// This is not a bitwise operation.
+/// Panics as [`Interval::translate`] does.
+impl<T: PartialOrd + Clone + Sub<Output = T>> Shl<T> for Interval<T> {
+    type Output = Self;
+
+    fn shl(self, offset: T) -> Self::Output {
+        self.map_endpoints(|value| value - offset.clone(), false)
+    }
+}
+
+impl<T: PartialOrd + Clone + Display> Interval<T> {
+    /// Formats the interval with the endpoint values rendered to the given number of decimal places,
+    /// e.g., `(0.12, 1.99)`.
+    /// The precision has no effect on integers.
+    pub fn format_precision(&self, decimals: usize) -> String {
+        let mut output = String::new();
+        // Writing to a string never fails
+        let _ = self.write_with_precision(&mut output, Some(decimals));
+
+        output
+    }
+
+    /// Writes the interval with the optional precision of the endpoint values.
+    fn write_with_precision(&self, f: &mut impl Write, precision: Option<usize>) -> std::fmt::Result {
+        self.write_with(f, |value| match precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        })
+    }
 }
 
 impl<T: PartialOrd + Clone + Display> Display for Interval<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match (&self.left, &self.right) {
-            (Endpoint::Open(low), Endpoint::Open(high)) => { write!(f, "({}, {})", low, high) }
-            (Endpoint::Open(low), Endpoint::Closed(high)) => { write!(f, "({}, {}]", low, high) }
-            (Endpoint::Closed(low), Endpoint::Open(high)) => { write!(f, "[{}, {})", low, high) }
-            (Endpoint::Closed(low), Endpoint::Closed(high)) => if low == high {
-                write!(f, "[{}]", low)
-            } else {
-                write!(f, "[{}, {}]", low, high)
+        self.write_with_precision(f, None)
+    }
+}
+
+/// Orders the intervals by their left endpoints first, and then by their right endpoints,
+/// which is the order of the intervals in a normalized set.
+/// The left endpoints are ordered as in [`Endpoint::cmp_as_left`], i.e.,
+/// an unbounded one comes first, and a closed one comes before an open one with the same value,
+/// e.g., `[0, 1)` < `(0, 1)`.
+/// The right endpoints are ordered as in [`Endpoint::cmp_as_right`], i.e.,
+/// an open one comes before a closed one with the same value, and an unbounded one comes last,
+/// e.g., `[0, 1)` < `[0, 1]` < `[0, 2]` < `[0, +∞)`.
+/// Returns `None` if the values of the left or right endpoints cannot be compared.
+impl<T: PartialOrd + Clone> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        for (this, that) in [(&self.left, &other.left), (&self.right, &other.right)] {
+            if let (Some(this), Some(that)) = (this.value(), that.value()) {
+                this.partial_cmp(that)?;
             }
-            (Endpoint::Unbounded, Endpoint::Open(high)) => { write!(f, "(-∞, {})", high) }
-            (Endpoint::Unbounded, Endpoint::Closed(high)) => { write!(f, "(-∞, {}]", high) }
-            (Endpoint::Open(low), Endpoint::Unbounded) => { write!(f, "({}, +∞)", low) }
-            (Endpoint::Closed(low), Endpoint::Unbounded) => { write!(f, "[{}, +∞)", low) }
-            (Endpoint::Unbounded, Endpoint::Unbounded) => { write!(f, "(-∞, +∞)") }
         }
+
+        Some(self.cmp_left_endpoints(other).then_with(|| self.cmp_right_endpoints(other)))
     }
 }
 
+/// Checks that the value is comparable with itself, i.e., it is not like NaN.
+fn check_value<T: PartialOrd>(value: &T) -> IntervalSetResult<()> {
+    match value.partial_cmp(value) {
+        Some(_) => Ok(()),
+        None => Err(IntervalSetError::NonComparableEndpoint),
+    }
+}
+
+/// Checks that the value of the endpoint, if any, is comparable with itself.
+fn check_endpoint<T: PartialOrd + Clone>(endpoint: &Endpoint<T>) -> IntervalSetResult<()> {
+    endpoint.value().map_or(Ok(()), check_value)
+}
+
+/// Panics if the value is not comparable with itself,
+/// for the constructors that cannot fail otherwise.
+fn assert_comparable<T: PartialOrd>(value: &T) {
+    assert!(check_value(value).is_ok(), "endpoint value is not comparable with itself");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -442,7 +842,7 @@ mod tests {
         assert!(interval.is_ok());
 
         let interval = Interval::<i32>::new(Endpoint::Open(0), Endpoint::Open(0));
-        assert!(interval.is_err());
+        assert_eq!(interval.unwrap_err(), IntervalSetError::InvalidInterval);
 
         let interval = Interval::<i32>::new(Endpoint::Closed(0), Endpoint::Closed(0));
         assert!(interval.is_ok());
@@ -451,6 +851,85 @@ mod tests {
         assert!(interval.is_ok());
     }
 
+    #[test]
+    fn reject_nan() {
+        let nan = f64::NAN;
+        let error = Err(IntervalSetError::NonComparableEndpoint);
+
+        assert_eq!(Interval::new(Endpoint::Open(nan), Endpoint::Unbounded), error);
+        assert_eq!(Interval::new(Endpoint::Unbounded, Endpoint::Closed(nan)), error);
+        assert_eq!(Interval::new(Endpoint::Closed(0.0), Endpoint::Closed(nan)), error);
+        assert_eq!(Interval::new_by(Endpoint::Open(nan), Endpoint::Unbounded, f64::total_cmp), error);
+
+        for (low, high) in [(nan, 1.0), (0.0, nan), (nan, nan)] {
+            assert_eq!(Interval::open(low, high), error);
+            assert_eq!(Interval::closed(low, high), error);
+            assert_eq!(Interval::open_closed(low, high), error);
+            assert_eq!(Interval::closed_open(low, high), error);
+        }
+
+        // The constructors that cannot fail panic instead
+        let constructors: [fn(f64) -> Interval<f64>; 7] = [
+            Interval::unbounded_open,
+            Interval::unbounded_closed,
+            Interval::open_unbounded,
+            Interval::closed_unbounded,
+            Interval::at_least,
+            Interval::at_most,
+            Interval::up_to,
+        ];
+        for constructor in constructors {
+            assert!(std::panic::catch_unwind(|| constructor(nan)).is_err());
+            assert!(std::panic::catch_unwind(|| constructor(0.0)).is_ok());
+        }
+
+        // Infinities are comparable
+        assert!(Interval::closed(f64::NEG_INFINITY, f64::INFINITY).is_ok());
+    }
+
+    #[test]
+    fn test_new_by() {
+        let interval = Interval::<f64>::new_by(Endpoint::Open(0.0), Endpoint::Closed(1.0), f64::total_cmp);
+        assert_eq!(interval.unwrap(), Interval::<f64>::open_closed(0.0, 1.0).unwrap());
+
+        let interval = Interval::<f64>::new_by(Endpoint::Closed(1.0), Endpoint::Closed(0.0), f64::total_cmp);
+        assert!(interval.is_err());
+
+        // NaN is rejected
+        let interval = Interval::<f64>::new_by(Endpoint::Closed(0.0), Endpoint::Closed(f64::NAN), f64::total_cmp);
+        assert!(interval.is_err());
+
+        let interval = Interval::<f64>::new_by(Endpoint::Open(f64::NAN), Endpoint::Unbounded, f64::total_cmp);
+        assert!(interval.is_err());
+
+        // A comparator disagreeing with the natural order is rejected
+        let interval = Interval::<i32>::new_by(Endpoint::Closed(5), Endpoint::Open(1), |a, b| b.cmp(a));
+        assert_eq!(interval, Err(IntervalSetError::InconsistentComparator));
+        let interval = Interval::<i32>::new_by(Endpoint::Closed(1), Endpoint::Open(5), |a, b| b.cmp(a));
+        assert_eq!(interval, Err(IntervalSetError::InconsistentComparator));
+
+        // Signed zeros are equal in the natural order but not in the total order
+        let interval = Interval::<f64>::new_by(Endpoint::Closed(-0.0), Endpoint::Closed(0.0), f64::total_cmp);
+        assert_eq!(interval, Err(IntervalSetError::InconsistentComparator));
+
+        let interval = Interval::<f64>::new_by(Endpoint::Unbounded, Endpoint::Open(1.0), f64::total_cmp);
+        assert_eq!(interval, Ok(Interval::unbounded_open(1.0)));
+    }
+
+    #[test]
+    fn test_ordered_closed() {
+        assert_eq!(Interval::<i32>::ordered_closed(5, 2), Interval::closed(2, 5).unwrap());
+        assert_eq!(Interval::<i32>::ordered_closed(2, 5), Interval::closed(2, 5).unwrap());
+        assert!(Interval::<i32>::ordered_closed(3, 3).is_degenerate());
+
+        assert_eq!(Interval::ordered_closed(1.5, -0.5), Interval::closed(-0.5, 1.5).unwrap());
+
+        // NaN has no place in either order
+        assert!(std::panic::catch_unwind(|| Interval::ordered_closed(f64::NAN, 0.0)).is_err());
+        assert!(std::panic::catch_unwind(|| Interval::ordered_closed(0.0, f64::NAN)).is_err());
+        assert!(std::panic::catch_unwind(|| Interval::ordered_closed(f64::NAN, f64::NAN)).is_err());
+    }
+
     #[test]
     fn test_display() {
         let interval = Interval::<i32>::new(Endpoint::Open(0), Endpoint::Open(1));
@@ -547,6 +1026,24 @@ mod tests {
         let c = a.merge(&b);
         assert!(c.is_err());
 
+        let a = Interval::<i32>::open(0, 1).unwrap();
+        let b = Interval::<i32>::open(2, 3).unwrap();
+        let c = a.merge(&b);
+        assert_eq!(c.unwrap_err().to_string(), "cannot merge separated intervals (0, 1) and (2, 3)");
+
+        let a = Interval::<i32>::unbounded_closed(0);
+        let b = Interval::<i32>::open_unbounded(1);
+        let c = a.merge(&b);
+        assert_eq!(c.unwrap_err().to_string(), "cannot merge separated intervals (-∞, 0] and (1, +∞)");
+
+        // The values need not be displayable, as `Duration` is not
+        use std::time::Duration;
+        let a = Interval::closed(Duration::from_secs(0), Duration::from_secs(2)).unwrap();
+        let b = Interval::closed(Duration::from_secs(1), Duration::from_secs(3)).unwrap();
+        assert_eq!(a.merge(&b).unwrap(), Interval::closed(Duration::from_secs(0), Duration::from_secs(3)).unwrap());
+        let c = Interval::open(Duration::from_secs(5), Duration::from_secs(6)).unwrap();
+        assert_eq!(a.merge(&c).unwrap_err().to_string(), "cannot merge separated intervals [0ns, 2s] and (5s, 6s)");
+
         let a = Interval::<i32>::open(0, 1).unwrap();
         let b = Interval::<i32>::closed(1, 2).unwrap();
         let c = a.merge(&b);
@@ -554,4 +1051,433 @@ mod tests {
         let c = c.unwrap();
         assert_eq!(c, Interval::<i32>::open_closed(0, 2).unwrap());
     }
+
+    #[test]
+    fn test_contains() {
+        let interval = Interval::<i32>::open(0, 1).unwrap();
+        assert!(!interval.contains(&0));
+        assert!(!interval.contains(&1));
+
+        let interval = Interval::<i32>::closed(0, 1).unwrap();
+        assert!(interval.contains(&0));
+        assert!(interval.contains(&1));
+        assert!(!interval.contains(&2));
+
+        let interval = Interval::<i32>::unbounded_open(5);
+        assert!(interval.contains(&-100));
+        assert!(!interval.contains(&5));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let interval = Interval::closed(0, 10).unwrap();
+        assert_eq!(
+            interval.split_at(5),
+            Some((Interval::closed_open(0, 5).unwrap(), Interval::closed(5, 10).unwrap()))
+        );
+
+        let interval = Interval::open_closed(0.0, 1.0).unwrap();
+        assert_eq!(
+            interval.split_at(0.25),
+            Some((Interval::open(0.0, 0.25).unwrap(), Interval::closed(0.25, 1.0).unwrap()))
+        );
+
+        // The pieces are disjoint and make up the interval
+        let (left, right) = Interval::open(0, 3).unwrap().split_at(1).unwrap();
+        assert_eq!(left, Interval::open(0, 1).unwrap());
+        assert_eq!(right, Interval::closed_open(1, 3).unwrap());
+        assert!(!left.overlaps(&right));
+        assert_eq!(left.merge_unchecked(&right), Interval::open(0, 3).unwrap());
+
+        // On an endpoint or outside
+        let interval = Interval::closed(0, 10).unwrap();
+        assert_eq!(interval.split_at(0), None);
+        assert_eq!(interval.split_at(10), None);
+        assert_eq!(interval.split_at(-1), None);
+        assert_eq!(interval.split_at(11), None);
+        assert_eq!(Interval::open(0, 10).unwrap().split_at(0), None);
+        assert_eq!(Interval::open(0, 10).unwrap().split_at(10), None);
+        assert_eq!(Interval::closed(2, 2).unwrap().split_at(2), None);
+
+        // Unbounded sides
+        assert_eq!(
+            Interval::unbounded_closed(10).split_at(0),
+            Some((Interval::unbounded_open(0), Interval::closed(0, 10).unwrap()))
+        );
+        assert_eq!(
+            Interval::<i32>::universe().split_at(0),
+            Some((Interval::unbounded_open(0), Interval::closed_unbounded(0)))
+        );
+    }
+
+    #[test]
+    fn test_clamp() {
+        let interval = Interval::closed(0, 10).unwrap();
+        assert_eq!(interval.clamp(15), 10);
+        assert_eq!(interval.clamp(-3), 0);
+        assert_eq!(interval.clamp(4), 4);
+        assert_eq!(interval.clamp(10), 10);
+
+        // Open endpoints clamp to their boundary values
+        let interval = Interval::open(0, 10).unwrap();
+        assert_eq!(interval.clamp(15), 10);
+        assert_eq!(interval.clamp(-3), 0);
+        assert_eq!(interval.clamp(0), 0);
+        assert_eq!(interval.clamp(5), 5);
+
+        let interval = Interval::unbounded_closed(5);
+        assert_eq!(interval.clamp(8), 5);
+        assert_eq!(interval.clamp(-1000), -1000);
+
+        let interval = Interval::open_unbounded(0.5);
+        assert_eq!(interval.clamp(0.0), 0.5);
+        assert_eq!(interval.clamp(1e9), 1e9);
+        assert!(interval.clamp(f64::NAN).is_nan());
+
+        assert_eq!(Interval::<i32>::universe().clamp(i32::MAX), i32::MAX);
+    }
+
+    #[test]
+    fn test_contains_all() {
+        let interval = Interval::closed_open(0, 10).unwrap();
+        assert!(interval.contains_all(&[0, 5, 9]));
+        assert!(interval.contains_all(&[]));
+
+        // 10 is on the open boundary
+        assert!(!interval.contains_all(&[0, 5, 10]));
+
+        let interval = Interval::closed(0, 10).unwrap();
+        assert!(interval.contains_all(&[0, 5, 10]));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        // Overlapping
+        let a = Interval::<i32>::closed(0, 1).unwrap();
+        let b = Interval::<i32>::closed(1, 2).unwrap();
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(Interval::closed(0, 5).unwrap().overlaps(&Interval::open(1, 2).unwrap()));
+        assert!(Interval::<i32>::unbounded_open(1).overlaps(&Interval::open_unbounded(0)));
+
+        // Touching but not overlapping
+        let a = Interval::<i32>::closed_open(0, 1).unwrap();
+        assert!(!a.overlaps(&b));
+        assert!(!a.is_separated_from(&b));
+        assert!(!Interval::<i32>::unbounded_open(0).overlaps(&Interval::closed_unbounded(0)));
+
+        // Separated
+        let a = Interval::<i32>::open(0, 1).unwrap();
+        let b = Interval::<i32>::open(1, 2).unwrap();
+        assert!(!a.overlaps(&b));
+        assert!(!Interval::closed(0, 1).unwrap().overlaps(&Interval::closed(3, 4).unwrap()));
+    }
+
+    #[test]
+    fn test_touches() {
+        // Adjacent
+        let a = Interval::<i32>::closed(0, 1).unwrap();
+        let b = Interval::<i32>::closed(1, 2).unwrap();
+        assert!(a.touches(&b));
+        assert!(b.touches(&a));
+
+        let a = Interval::<i32>::closed_open(0, 1).unwrap();
+        let b = Interval::<i32>::closed(1, 2).unwrap();
+        assert!(a.touches(&b));
+
+        let a = Interval::<i32>::unbounded_open(0);
+        let b = Interval::<i32>::closed_unbounded(0);
+        assert!(a.touches(&b));
+
+        // Overlapping
+        let a = Interval::<i32>::closed(0, 2).unwrap();
+        let b = Interval::<i32>::closed(1, 3).unwrap();
+        assert!(!a.touches(&b));
+
+        let a = Interval::<i32>::universe();
+        let b = Interval::<i32>::closed(1, 3).unwrap();
+        assert!(!a.touches(&b));
+
+        // A point strictly inside the other interval
+        let a = Interval::<i32>::closed(1, 1).unwrap();
+        let b = Interval::<i32>::closed(0, 2).unwrap();
+        assert!(!a.touches(&b));
+        assert!(!b.touches(&a));
+
+        // A point on the boundary of the other interval
+        let b = Interval::<i32>::closed_open(0, 1).unwrap();
+        assert!(a.touches(&b));
+        assert!(b.touches(&a));
+
+        // Separated
+        let a = Interval::<i32>::open(0, 1).unwrap();
+        let b = Interval::<i32>::open(1, 2).unwrap();
+        assert!(!a.touches(&b));
+
+        let a = Interval::<i32>::closed(0, 1).unwrap();
+        let b = Interval::<i32>::closed(3, 4).unwrap();
+        assert!(!a.touches(&b));
+    }
+
+    #[test]
+    fn test_gap_to() {
+        // Separated
+        let a = Interval::<i32>::closed(0, 1).unwrap();
+        let b = Interval::<i32>::closed(3, 4).unwrap();
+        assert_eq!(a.gap_to(&b), Some(Interval::open(1, 3).unwrap()));
+        assert_eq!(b.gap_to(&a), Some(Interval::open(1, 3).unwrap()));
+
+        let a = Interval::<i32>::unbounded_open(1);
+        let b = Interval::<i32>::open_unbounded(1);
+        assert_eq!(a.gap_to(&b), Some(Interval::closed(1, 1).unwrap()));
+
+        let a = Interval::<i32>::closed_open(0, 1).unwrap();
+        let b = Interval::<i32>::open(2, 3).unwrap();
+        assert_eq!(a.gap_to(&b), Some(Interval::closed(1, 2).unwrap()));
+
+        // Touching
+        let a = Interval::<i32>::closed(0, 1).unwrap();
+        let b = Interval::<i32>::closed(1, 2).unwrap();
+        assert_eq!(a.gap_to(&b), None);
+
+        let a = Interval::<i32>::closed_open(0, 1).unwrap();
+        let b = Interval::<i32>::closed(1, 2).unwrap();
+        assert_eq!(a.gap_to(&b), None);
+
+        // Overlapping
+        let a = Interval::<i32>::closed(0, 2).unwrap();
+        let b = Interval::<i32>::closed(1, 3).unwrap();
+        assert_eq!(a.gap_to(&b), None);
+    }
+
+    #[test]
+    fn test_format_precision() {
+        let interval = Interval::open(0.123456, 1.987654).unwrap();
+        assert_eq!(interval.format_precision(2), "(0.12, 1.99)");
+
+        let interval = Interval::unbounded_closed(2.5);
+        assert_eq!(interval.format_precision(0), "(-∞, 2]");
+
+        let interval = Interval::closed(1, 3).unwrap();
+        assert_eq!(interval.format_precision(2), "[1, 3]");
+    }
+
+    #[test]
+    fn test_at_least_and_at_most() {
+        assert!(Interval::at_least(5).contains(&1000000));
+        assert!(Interval::at_least(5).contains(&i32::MAX));
+        assert!(!Interval::at_least(5).contains(&4));
+
+        assert_eq!(Interval::at_most(5), Interval::unbounded_closed(5));
+        assert!(Interval::at_most(5).contains(&i32::MIN));
+        assert!(Interval::up_to(5).contains(&4));
+        assert!(!Interval::up_to(5).contains(&5));
+    }
+
+    #[test]
+    fn test_try_cast() {
+        let interval = Interval::<i64>::closed_open(-5, 1 << 20).unwrap();
+        assert_eq!(interval.try_cast::<i32>(), Some(Interval::closed_open(-5, 1 << 20).unwrap()));
+
+        let interval = Interval::<i64>::open_unbounded(-1);
+        assert_eq!(interval.try_cast::<i32>(), Some(Interval::open_unbounded(-1)));
+        assert_eq!(interval.try_cast::<u32>(), None);
+
+        let interval = Interval::<i64>::closed(0, 1 << 40).unwrap();
+        assert_eq!(interval.try_cast::<i32>(), None);
+    }
+
+    #[test]
+    fn test_width() {
+        let interval = Interval::<i32>::open_closed(1, 4).unwrap();
+        assert_eq!(interval.width(), Some(3));
+
+        let interval = Interval::<i32>::unbounded_open(4);
+        assert_eq!(interval.width(), None);
+        assert_eq!(Interval::<i32>::closed_unbounded(4).width(), None);
+        assert_eq!(Interval::<i32>::universe().width(), None);
+
+        // Open and closed endpoints give the same width
+        assert_eq!(Interval::open(0, 5).unwrap().width(), Some(5));
+        assert_eq!(Interval::closed(0, 5).unwrap().width(), Some(5));
+        assert_eq!(Interval::closed(2, 2).unwrap().width(), Some(0));
+
+        assert_eq!(Interval::closed_open(-1.5, 2.25).unwrap().width(), Some(3.75));
+        assert_eq!(Interval::closed(0.5, 0.5).unwrap().width(), Some(0.0));
+        assert_eq!(Interval::open_unbounded(0.0).width(), None);
+    }
+
+    #[test]
+    fn test_partial_cmp() {
+        assert!(Interval::closed(0, 1).unwrap() < Interval::closed(0, 2).unwrap());
+        assert!(Interval::closed_open(0, 1).unwrap() < Interval::open(0, 1).unwrap());
+        assert!(Interval::closed_open(0, 1).unwrap() < Interval::closed(0, 1).unwrap());
+        assert!(Interval::unbounded_closed(5) < Interval::closed(0, 1).unwrap());
+        assert!(Interval::closed_unbounded(0) > Interval::closed(0, 100).unwrap());
+        assert_eq!(
+            Interval::closed(0, 1).unwrap().partial_cmp(&Interval::closed(0, 1).unwrap()),
+            Some(Ordering::Equal)
+        );
+
+        let expected = vec![
+            Interval::<i32>::unbounded_open(0),
+            Interval::<i32>::unbounded_closed(0),
+            Interval::<i32>::universe(),
+            Interval::<i32>::closed_open(0, 1).unwrap(),
+            Interval::<i32>::closed(0, 1).unwrap(),
+            Interval::<i32>::closed(0, 2).unwrap(),
+            Interval::<i32>::closed_unbounded(0),
+            Interval::<i32>::open(0, 1).unwrap(),
+            Interval::<i32>::open_closed(0, 1).unwrap(),
+            Interval::<i32>::closed(1, 1).unwrap(),
+            Interval::<i32>::open_unbounded(1)
+        ];
+
+        // Sorting any rotation or reversal gives the same order
+        for shift in 0..expected.len() {
+            let mut intervals = expected.clone();
+            intervals.rotate_left(shift);
+            if shift % 2 == 1 {
+                intervals.reverse();
+            }
+
+            intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(intervals, expected);
+        }
+
+        // Incomparable endpoint values make the intervals incomparable
+        let nan = Interval { left: Endpoint::Closed(f64::NAN), right: Endpoint::Closed(1.0) };
+        let interval = Interval::closed(0.0, 1.0).unwrap();
+        assert_eq!(nan.partial_cmp(&interval), None);
+        assert_eq!(interval.partial_cmp(&nan), None);
+
+        // Even if the other endpoints would decide the order
+        let nan = Interval { left: Endpoint::Closed(0.0), right: Endpoint::Closed(f64::NAN) };
+        assert_eq!(nan.partial_cmp(&Interval::closed(1.0, 2.0).unwrap()), None);
+        assert_eq!(nan.partial_cmp(&Interval::unbounded_closed(2.0)), None);
+        assert_eq!(nan.partial_cmp(&Interval::closed_unbounded(-1.0)), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Interval::closed(0, 5).unwrap();
+        let b = Interval::closed(3, 8).unwrap();
+        assert_eq!(a.intersection(&b), Some(Interval::closed(3, 5).unwrap()));
+        assert_eq!(b.intersection(&a), Some(Interval::closed(3, 5).unwrap()));
+
+        let a = Interval::open(0, 1).unwrap();
+        let b = Interval::open(1, 2).unwrap();
+        assert_eq!(a.intersection(&b), None);
+
+        // Touching at an open and a closed endpoint
+        let a = Interval::closed_open(0, 1).unwrap();
+        let b = Interval::closed(1, 2).unwrap();
+        assert_eq!(a.intersection(&b), None);
+
+        let a = Interval::closed(0, 2).unwrap();
+        let b = Interval::closed(2, 4).unwrap();
+        assert_eq!(a.intersection(&b), Some(Interval::closed(2, 2).unwrap()));
+
+        let a = Interval::closed(0, 1).unwrap();
+        let b = Interval::closed(5, 6).unwrap();
+        assert_eq!(a.intersection(&b), None);
+
+        // Unbounded operands
+        let a = Interval::unbounded_closed(3);
+        let b = Interval::closed_unbounded(0);
+        assert_eq!(a.intersection(&b), Some(Interval::closed(0, 3).unwrap()));
+        assert_eq!(a.intersection(&Interval::universe()), Some(a.clone()));
+        assert_eq!(
+            Interval::<i32>::universe().intersection(&Interval::universe()),
+            Some(Interval::universe())
+        );
+        assert_eq!(a.intersection(&Interval::open_unbounded(3)), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let interval = Interval::<i32>::closed(1, 4).unwrap();
+        assert_eq!(interval.measure(), Some(3));
+
+        let interval = Interval::<i64>::closed(2, 2).unwrap();
+        assert_eq!(interval.measure(), Some(0));
+
+        let interval = Interval::<f64>::open(0.5, 2.0).unwrap();
+        assert_eq!(interval.measure(), Some(1.5));
+
+        let interval = Interval::<f32>::open_unbounded(0.0);
+        assert_eq!(interval.measure(), None);
+    }
+
+    #[test]
+    fn test_translate() {
+        let interval = Interval::<i32>::closed_open(0, 2).unwrap();
+        assert_eq!(interval.translate(3), Interval::<i32>::closed_open(3, 5).unwrap());
+
+        let interval = Interval::<i32>::unbounded_closed(2);
+        assert_eq!(interval.translate(-1), Interval::<i32>::unbounded_closed(1));
+    }
+
+    #[test]
+    fn test_shift() {
+        let interval = Interval::<i32>::closed_open(0, 2).unwrap();
+        assert_eq!(interval.clone() >> 5, Interval::closed_open(5, 7).unwrap());
+        assert_eq!(interval << 5, Interval::closed_open(-5, -3).unwrap());
+
+        // Unbounded sides are preserved
+        let interval = Interval::<f64>::open_unbounded(1.0);
+        assert_eq!(interval.clone() >> 0.5, Interval::open_unbounded(1.5));
+        assert_eq!(interval << 0.5, Interval::open_unbounded(0.5));
+
+        let interval = Interval::<i32>::universe();
+        assert_eq!(interval >> 1, Interval::universe());
+    }
+
+    #[test]
+    fn test_reflect() {
+        let interval = Interval::<i32>::closed(1, 4).unwrap();
+        assert_eq!(interval.reflect(0), Interval::<i32>::closed(-4, -1).unwrap());
+
+        let interval = Interval::<i32>::closed_open(1, 4).unwrap();
+        assert_eq!(interval.reflect(2), Interval::<i32>::open_closed(0, 3).unwrap());
+
+        let interval = Interval::<f64>::unbounded_closed(1.0);
+        assert_eq!(interval.reflect(0.5), Interval::<f64>::closed_unbounded(0.0));
+    }
+
+    #[test]
+    fn reject_nan_when_mapping() {
+        let interval = Interval::closed(0.0, 1.0).unwrap();
+        assert!(std::panic::catch_unwind(|| interval.translate(f64::NAN)).is_err());
+        assert!(std::panic::catch_unwind(|| interval.reflect(f64::NAN)).is_err());
+        assert!(std::panic::catch_unwind(|| interval.clone() >> f64::NAN).is_err());
+        assert!(std::panic::catch_unwind(|| interval.clone() << f64::NAN).is_err());
+
+        assert_eq!(interval.dilate(f64::NAN), None);
+
+        // Infinities of opposite signs add up to NaN
+        let interval = Interval::closed(0.0, f64::INFINITY).unwrap();
+        assert!(std::panic::catch_unwind(|| interval.translate(f64::NEG_INFINITY)).is_err());
+
+        // Unbounded sides are not mapped
+        let interval = Interval::closed_unbounded(0.0);
+        assert_eq!(interval.translate(f64::INFINITY), Interval::closed_unbounded(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_dilate() {
+        let interval = Interval::<i32>::closed_open(0, 10).unwrap();
+        assert_eq!(interval.dilate(2), Some(Interval::closed_open(-2, 12).unwrap()));
+
+        let interval = Interval::<f64>::unbounded_closed(1.0);
+        assert_eq!(interval.dilate(0.5), Some(Interval::unbounded_closed(1.5)));
+
+        // A negative margin erodes the interval until nothing is left
+        let interval = Interval::<i32>::closed(0, 10).unwrap();
+        assert_eq!(interval.dilate(-2), Some(Interval::closed(2, 8).unwrap()));
+        assert_eq!(interval.dilate(-5), Some(Interval::closed(5, 5).unwrap()));
+        assert_eq!(interval.dilate(-6), None);
+        assert_eq!(Interval::<i32>::closed_open(0, 10).unwrap().dilate(-5), None);
+        assert_eq!(Interval::<i32>::closed_unbounded(0).dilate(-3), Some(Interval::closed_unbounded(3)));
+    }
 }
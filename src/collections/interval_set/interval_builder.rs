@@ -0,0 +1,104 @@
+use super::{ Endpoint, Interval, IntervalSetResult };
+
+/// A builder that sets the endpoints of an interval one side at a time.
+/// Unspecified sides are unbounded.
+/// This struct is created by [`Interval::builder`].
+#[derive(Debug, Clone)]
+pub struct IntervalBuilder<T: PartialOrd + Clone> {
+    left: Endpoint<T>,
+    right: Endpoint<T>,
+}
+
+impl<T: PartialOrd + Clone> Interval<T> {
+    /// Creates a builder of an interval, e.g.,
+    /// `Interval::builder().left_open(0).right_closed(5).build()` gives `(0, 5]`.
+    pub fn builder() -> IntervalBuilder<T> {
+        IntervalBuilder {
+            left: Endpoint::Unbounded,
+            right: Endpoint::Unbounded,
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> IntervalBuilder<T> {
+    /// Makes the left endpoint open.
+    pub fn left_open(mut self, low: T) -> Self {
+        self.left = Endpoint::Open(low);
+        self
+    }
+
+    /// Makes the left endpoint closed.
+    pub fn left_closed(mut self, low: T) -> Self {
+        self.left = Endpoint::Closed(low);
+        self
+    }
+
+    /// Makes the left endpoint unbounded.
+    pub fn left_unbounded(mut self) -> Self {
+        self.left = Endpoint::Unbounded;
+        self
+    }
+
+    /// Makes the right endpoint open.
+    pub fn right_open(mut self, high: T) -> Self {
+        self.right = Endpoint::Open(high);
+        self
+    }
+
+    /// Makes the right endpoint closed.
+    pub fn right_closed(mut self, high: T) -> Self {
+        self.right = Endpoint::Closed(high);
+        self
+    }
+
+    /// Makes the right endpoint unbounded.
+    pub fn right_unbounded(mut self) -> Self {
+        self.right = Endpoint::Unbounded;
+        self
+    }
+
+    /// Builds the interval.
+    /// Returns an error if the endpoints do not form a valid interval.
+    pub fn build(self) -> IntervalSetResult<Interval<T>> {
+        Interval::new(self.left, self.right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::IntervalSetError;
+
+    #[test]
+    fn test_builder() {
+        let builder = || Interval::builder();
+
+        assert_eq!(builder().left_open(0).right_open(5).build(), Interval::open(0, 5));
+        assert_eq!(builder().left_open(0).right_closed(5).build(), Interval::open_closed(0, 5));
+        assert_eq!(builder().left_closed(0).right_open(5).build(), Interval::closed_open(0, 5));
+        assert_eq!(builder().left_closed(0).right_closed(5).build(), Interval::closed(0, 5));
+        assert_eq!(builder().left_unbounded().right_open(5).build(), Ok(Interval::unbounded_open(5)));
+        assert_eq!(builder().right_closed(5).build(), Ok(Interval::unbounded_closed(5)));
+        assert_eq!(builder().left_open(0).right_unbounded().build(), Ok(Interval::open_unbounded(0)));
+        assert_eq!(builder().left_closed(0).build(), Ok(Interval::closed_unbounded(0)));
+        assert_eq!(builder().build(), Ok(Interval::universe()));
+
+        // The side set last wins
+        assert_eq!(
+            builder().left_open(0).left_unbounded().right_closed(5).build(),
+            Ok(Interval::unbounded_closed(5))
+        );
+    }
+
+    #[test]
+    fn build_invalid_interval() {
+        let interval = Interval::builder().left_closed(5).right_open(5).build();
+        assert_eq!(interval, Err(IntervalSetError::InvalidInterval));
+
+        // Set the bounds conditionally
+        let inclusive = false;
+        let builder = Interval::builder().left_closed(1);
+        let builder = if inclusive { builder.right_closed(0) } else { builder.right_open(0) };
+        assert_eq!(builder.build(), Err(IntervalSetError::InvalidInterval));
+    }
+}
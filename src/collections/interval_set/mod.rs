@@ -5,10 +5,76 @@ mod endpoint;
 pub use endpoint::Endpoint;
 
 mod interval;
-use interval::Interval;
+pub use interval::Interval;
 
-use std::ops::{ BitAnd, BitOr };
+mod interval_builder;
+pub use interval_builder::IntervalBuilder;
 
+mod container;
+pub use container::IntervalContainer;
+
+mod coalesce;
+pub use coalesce::{
+    IntervalIteratorExt,
+    CoalesceIntervals,
+    MergePolicy,
+    MergedIntervals,
+    coalesce_sorted,
+    normalize_with_report,
+};
+
+mod timeline;
+pub use timeline::{ Timeline, Conflict };
+
+mod cover;
+pub use cover::{ is_exact_partition, min_intervals_to_cover };
+
+mod coverage;
+pub use coverage::{ coverage_over_time, covered_by_at_least };
+
+mod parse;
+
+mod bounded_interval;
+pub use bounded_interval::BoundedInterval;
+
+#[cfg(feature = "num-traits")]
+mod numeric;
+#[cfg(feature = "num-traits")]
+pub use numeric::{ Numeric, bisect, total_length_with_overlap };
+
+#[cfg(feature = "num-traits")]
+mod bytes;
+
+#[cfg(feature = "roaring")]
+mod roaring;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "rand")]
+mod rand;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{ Add, BitAnd, BitOr, BitXor, Bound, Not, RangeBounds, Sub };
+
+/// A set of points represented by sorted and separated intervals.
+///
+/// The set owns its intervals without any shared or interior mutability,
+/// so it is `Send` and `Sync` whenever `T` is,
+/// and an immutable set can be shared across threads freely.
+///
+/// Since the intervals are sorted, point queries such as [`IntervalSet::contains`]
+/// and [`IntervalSet::interval_at`] binary-search them in O(log n) time,
+/// where n is the number of intervals.
+/// Inserting or removing takes O(log n) time to locate the position
+/// plus the time to shift the intervals after it,
+/// and set operations such as [`IntervalSet::union`] take O(n + m) time.
+#[derive(Debug, Clone)]
 pub struct IntervalSet<T: PartialOrd + Clone> {
     intervals: Vec<Interval<T>>,
 }
@@ -78,12 +144,948 @@ impl<T: PartialOrd + Clone> IntervalSet<T> {
         }
     }
 
+    /// Creates an empty interval set.
+    pub fn empty() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Checks if the set contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Creates a new interval set from the intervals,
+    /// which may be unsorted and overlapping,
+    /// e.g., `[0, 2]`, `[10, 11]` and `[1, 3]` give `[0, 3] ∪ [10, 11]`.
+    /// The intervals are sorted by their left endpoints and
+    /// the ones not separated from each other are merged in a single sweep.
+    pub fn from_intervals(intervals: impl IntoIterator<Item = Interval<T>>) -> Self {
+        let mut set = Self { intervals: intervals.into_iter().collect() };
+        set.normalize();
+
+        set
+    }
+
+    /// Creates a new interval set from the intervals,
+    /// which may be unsorted and overlapping.
+    /// This always succeeds for now, but returns a result
+    /// so that validations can be added in the future.
+    pub fn try_from_intervals(intervals: Vec<Interval<T>>) -> IntervalSetResult<Self> {
+        Ok(Self::from_intervals(intervals))
+    }
+
+    /// Creates a new interval set from the intervals,
+    /// checking the endpoints of each interval with the comparator as [`Interval::new_by`] does.
+    /// The set is always normalized in the order of `PartialOrd`, which the comparator cannot change,
+    /// so an interval on whose endpoints the comparator disagrees with `PartialOrd` is rejected.
+    pub fn with_comparator(
+        intervals: impl IntoIterator<Item = Interval<T>>,
+        cmp: impl Fn(&T, &T) -> Ordering
+    ) -> IntervalSetResult<Self> {
+        let intervals = intervals
+            .into_iter()
+            .map(|interval| Interval::new_by(interval.left().clone(), interval.right().clone(), &cmp))
+            .collect::<IntervalSetResult<Vec<_>>>()?;
+
+        Ok(Self::from_intervals(intervals))
+    }
+
+    /// Iterates over the intervals of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Gets the interval in the set that contains the value.
+    /// If the value lies in none of the intervals, returns `None`.
+    /// This takes O(log n) time since the intervals are sorted.
+    pub fn interval_at(&self, value: &T) -> Option<&Interval<T>> {
+        self.locate(value).ok().map(|index| &self.intervals[index])
+    }
+
+    /// Locates the value among the intervals by binary search in O(log n) time.
+    /// Returns `Ok` with the index of the interval containing the value,
+    /// or `Err` with the index where an interval containing it would be inserted.
+    fn locate(&self, value: &T) -> Result<usize, usize> {
+        // Count the intervals that do not lie entirely to the right of the value
+        let count = self.intervals.partition_point(|interval| !interval.is_after(value));
+
+        // Only the last one of them may contain the value
+        match count.checked_sub(1) {
+            Some(index) if self.intervals[index].contains(value) => Ok(index),
+            _ => Err(count),
+        }
+    }
+
+    /// Checks if the value lies in any interval of the set.
+    /// This takes O(log n) time since the intervals are sorted.
+    pub fn contains(&self, value: &T) -> bool {
+        self.interval_at(value).is_some()
+    }
+
+    /// Counts the query intervals that overlap the set,
+    /// e.g., how many proposed bookings conflict with the existing ones.
+    /// Each query takes O(log n) time.
+    pub fn count_overlapping(&self, queries: &[Interval<T>]) -> usize {
+        queries
+            .iter()
+            .filter(|query| self.overlaps(query))
+            .count()
+    }
+
+    /// Finds the first request overlapping the set or an earlier request,
+    /// and returns its index and itself,
+    /// so that a batch of bookings can be validated before committing any of them.
+    /// Each request is checked and inserted at its place found by binary search,
+    /// so no request rebuilds the booked intervals.
+    pub fn find_first_conflict(&self, requests: &[Interval<T>]) -> Option<(usize, Interval<T>)> {
+        // The union of the set and the requests accepted so far
+        let mut booked = self.clone();
+
+        for (index, request) in requests.iter().enumerate() {
+            if booked.overlaps(request) {
+                return Some((index, request.clone()));
+            }
+
+            booked.insert(request.clone());
+        }
+
+        None
+    }
+
+    /// Checks if the interval overlaps the set in O(log n) time.
+    fn overlaps(&self, other: &Interval<T>) -> bool {
+        // Only the first interval not lying before the other may overlap it
+        let index = self.count_intervals_before(other);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.overlaps(other))
+    }
+
+    /// Counts the intervals lying entirely before the given interval.
+    fn count_intervals_before(&self, other: &Interval<T>) -> usize {
+        self.intervals.partition_point(|interval| {
+            !interval.overlaps(other) &&
+                interval.cmp_left_endpoints(other) == Ordering::Less
+        })
+    }
+
+    /// Gets the interval closest to the value,
+    /// which is the interval containing the value if there is one.
+    /// Otherwise, the gaps to the neighboring intervals on both sides are compared,
+    /// and the left one wins a tie.
+    /// Returns `None` if the set is empty.
+    pub fn nearest_interval(&self, value: &T) -> Option<&Interval<T>>
+        where T: Sub<Output = T> + Ord
+    {
+        let count = match self.locate(value) {
+            Ok(index) => {
+                return Some(&self.intervals[index]);
+            }
+            Err(count) => count,
+        };
+        let before = count.checked_sub(1).map(|index| &self.intervals[index]);
+        let after = self.intervals.get(count);
+
+        match (before, after) {
+            (Some(before), Some(after)) => {
+                // Both are bounded on the sides facing the value
+                let gap_before = value.clone() - before.high()?;
+                let gap_after = after.low()? - value.clone();
+                if gap_after < gap_before { Some(after) } else { Some(before) }
+            }
+            (before, after) => before.or(after),
+        }
+    }
+
+    /// Samples the membership of the points `start`, `start + step`, `start + 2 * step`, ...
+    /// for `count` points.
+    /// A sample lying exactly on an open endpoint is not in the set,
+    /// while one on a closed endpoint is.
+    pub fn to_bool_grid(&self, start: T, step: T, count: usize) -> Vec<bool>
+        where T: Add<Output = T>
+    {
+        let mut grid = Vec::with_capacity(count);
+
+        let mut point = start;
+        for i in 0..count {
+            grid.push(self.contains(&point));
+
+            if i + 1 < count {
+                point = point + step.clone();
+            }
+        }
+
+        grid
+    }
+
+    /// Checks if every point in the range is in the set.
+    /// An empty range, e.g., `5..5`, is always contained.
+    pub fn contains_range(&self, range: impl RangeBounds<T>) -> bool {
+        let to_endpoint = |bound: Bound<&T>| {
+            match bound {
+                Bound::Included(value) => Endpoint::Closed(value.clone()),
+                Bound::Excluded(value) => Endpoint::Open(value.clone()),
+                Bound::Unbounded => Endpoint::Unbounded,
+            }
+        };
+
+        let Ok(range) = Interval::new(
+            to_endpoint(range.start_bound()),
+            to_endpoint(range.end_bound())
+        ) else {
+            return true;
+        };
+
+        self.contains_interval(&range)
+    }
+
+    /// Checks if every point in the interval is in the set.
+    /// This takes O(log n) time since the intervals are sorted.
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        // Count the intervals that start no later than the given one
+        let count = self.intervals.partition_point(
+            |other| other.cmp_left_endpoints(interval) != Ordering::Greater
+        );
+
+        // Since the interval is connected,
+        // it must be covered by the last one of them if it is covered at all
+        match count.checked_sub(1) {
+            Some(index) => self.intervals[index].cmp_right_endpoints(interval) != Ordering::Less,
+            None => false,
+        }
+    }
+
+    /// Checks if every point in this set is in the other set,
+    /// i.e., every interval of this set is covered by an interval of the other.
+    /// This takes O(m log n) time for sets of m and n intervals.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.intervals.iter().all(|interval| other.contains_interval(interval))
+    }
+
+    /// Merges the whole set into a single interval.
+    /// Returns an error if the set is empty or has gaps.
+    pub fn merge_into_one(&self) -> IntervalSetResult<Interval<T>> {
+        match self.intervals.as_slice() {
+            [interval] => Ok(interval.clone()),
+            intervals => Err(IntervalSetError::NotConnected {
+                components: intervals.len(),
+            }),
+        }
+    }
+
+    /// Gets the number of gaps between the intervals in the set.
+    pub fn fragmentation(&self) -> usize {
+        self.intervals.len().saturating_sub(1)
+    }
+
+    /// Gets the bounded gaps between consecutive intervals in the set in ascending order,
+    /// whose endpoints have the opposite openness of the neighboring endpoints,
+    /// e.g., the gap of `[0, 2) ∪ (3, 5]` is `[2, 3]`.
+    /// Unlike the complement, the unbounded parts before the first interval
+    /// and after the last one are not included.
+    pub fn gaps(&self) -> Vec<Interval<T>> {
+        self.intervals
+            .windows(2)
+            // It is safe to unwrap since the intervals are separated, and
+            // the gap between them is bounded
+            .map(|pair| pair[0].gap_to(&pair[1]).unwrap())
+            .collect()
+    }
+
+    /// Gets the widest gap between the intervals in the set.
+    /// If there are several widest gaps, returns the first one.
+    /// If there are no gaps, returns `None`.
+    pub fn largest_gap(&self) -> Option<Interval<T>> where T: Sub<Output = T> {
+        let mut largest: Option<(Interval<T>, T)> = None;
+
+        for gap in self.gaps() {
+            // It is safe to unwrap since the gap is bounded
+            let width = gap.width().unwrap();
+
+            if !matches!(&largest, Some((_, largest_width)) if &width <= largest_width) {
+                largest = Some((gap, width));
+            }
+        }
+
+        largest.map(|(gap, _)| gap)
+    }
+
+    /// Formats the set with the endpoint values rendered to the given number of decimal places,
+    /// e.g., `[0.00, 1.00] ∪ (2.50, 3.00)`.
+    /// The empty set is formatted as `∅`.
+    pub fn format_precision(&self, decimals: usize) -> String where T: Display {
+        if self.intervals.is_empty() {
+            return "∅".to_string();
+        }
+
+        self.intervals
+            .iter()
+            .map(|interval| interval.format_precision(decimals))
+            .collect::<Vec<_>>()
+            .join(" ∪ ")
+    }
+
+    /// Finds the earliest free slot of the given duration inside `within`,
+    /// regarding this set as the busy time.
+    /// The slot starts where the first long enough gap starts, with the same openness.
+    pub fn first_fit(&self, duration: T, within: &Interval<T>) -> Option<Interval<T>>
+        where T: Add<Output = T> + Sub<Output = T>
+    {
+        let free = Self::from(within.clone()).difference(self);
+
+        free.intervals.iter().find_map(|gap| {
+            // A gap unbounded on the left has no earliest start
+            let low = gap.low()?;
+            let end = low.clone() + duration.clone();
+
+            match gap.high() {
+                Some(high) if high < end => None,
+                // The slot ends exactly where the gap ends
+                Some(high) if high == end => Some(gap.clone()),
+                _ => Interval::new(gap.left().clone(), Endpoint::Closed(end)).ok(),
+            }
+        })
+    }
+
+    /// Finds the widest free slot inside `within`,
+    /// regarding this set as the busy time.
+    /// An unbounded slot is the widest, and the earliest slot wins a tie.
+    /// Returns `None` if `within` is fully covered.
+    pub fn largest_free_slot(&self, within: &Interval<T>) -> Option<Interval<T>>
+        where T: Sub<Output = T>
+    {
+        let free = Self::from(within.clone()).difference(self);
+
+        let mut largest: Option<(Interval<T>, Option<T>)> = None;
+        for slot in free.intervals {
+            let width = slot.width();
+            let is_wider = match (&largest, &width) {
+                (None, _) => true,
+                (Some((_, None)), _) => false,
+                (Some((_, Some(_))), None) => true,
+                (Some((_, Some(largest_width))), Some(width)) => width > largest_width,
+            };
+            if is_wider {
+                largest = Some((slot, width));
+            }
+        }
+
+        largest.map(|(slot, _)| slot)
+    }
+
+    /// Gets the width of each interval in the set,
+    /// where the width of an unbounded interval is `None`.
+    pub fn component_widths(&self) -> impl Iterator<Item = Option<T>> + '_ where T: Sub<Output = T> {
+        self.intervals.iter().map(Interval::width)
+    }
+
+    /// Gets the measure, i.e., the total length of the intervals in the set.
+    /// The sum of the widths suffices since the intervals are separated.
+    /// The default value of `T` is regarded as zero, which is the measure of the empty set.
+    /// If any interval is unbounded, returns `None`.
+    pub fn measure(&self) -> Option<T> where T: Add<Output = T> + Sub<Output = T> + Default {
+        self.intervals
+            .iter()
+            .try_fold(T::default(), |total, interval| Some(total + interval.width()?))
+    }
+
+    /// Gets the set without the isolated points, i.e., the degenerate intervals.
+    pub fn without_points(&self) -> Self {
+        Self {
+            intervals: self.intervals
+                .iter()
+                .filter(|interval| !interval.is_degenerate())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Checks if the two sets are equal after removing their isolated points.
+    /// For example, `[0, 1] ∪ [5]` is regarded as equal to `[0, 1]`.
+    pub fn eq_ignoring_points(&self, other: &Self) -> bool {
+        self.without_points().intervals == other.without_points().intervals
+    }
+
+    /// Gets the values of all bounded endpoints in ascending order,
+    /// each paired with the endpoint itself to tell whether it is open or closed.
+    /// Both endpoints of a degenerate interval are included.
+    pub fn boundary_points(&self) -> Vec<(T, Endpoint<T>)> {
+        self.intervals
+            .iter()
+            .flat_map(|interval| [interval.left(), interval.right()])
+            .filter_map(|endpoint| {
+                match endpoint {
+                    Endpoint::Open(value) | Endpoint::Closed(value) =>
+                        Some((value.clone(), endpoint.clone())),
+                    Endpoint::Unbounded => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the low and high values of each interval in the set,
+    /// where an unbounded side is represented by `None`.
+    /// The openness of the endpoints is discarded.
+    pub fn to_pairs(&self) -> Vec<(Option<T>, Option<T>)> {
+        self.intervals
+            .iter()
+            .map(|interval| (interval.low(), interval.high()))
+            .collect()
+    }
+
+    /// Gets the low and high values of each interval in the set.
+    /// If any interval is unbounded, returns `None`.
+    /// The openness of the endpoints is discarded.
+    pub fn to_closed_pairs(&self) -> Option<Vec<(T, T)>> {
+        self.intervals
+            .iter()
+            .map(|interval| Some((interval.low()?, interval.high()?)))
+            .collect()
+    }
+
+    /// Translates all intervals in the set by the offset.
+    /// Panics as [`Interval::translate`] does.
+    pub fn translate_all(&self, offset: T) -> Self where T: Add<Output = T> {
+        let mut set = Self {
+            intervals: self.intervals
+                .iter()
+                .map(|interval| interval.translate(offset.clone()))
+                .collect(),
+        };
+        set.normalize();
+
+        set
+    }
+
+    /// Reflects all intervals in the set around the center.
+    /// See [`Interval::reflect`], including when it panics.
+    pub fn reflect(&self, center: T) -> Self where T: Add<Output = T> + Sub<Output = T> {
+        let mut set = Self {
+            intervals: self.intervals
+                .iter()
+                .map(|interval| interval.reflect(center.clone()))
+                .collect(),
+        };
+        set.normalize();
+
+        set
+    }
+
+    /// Maps every point of the set by the strictly monotone function `f`.
+    /// If `f` is decreasing, e.g., `|x| -x`, `increasing` must be `false`
+    /// so that the endpoints swap sides and the intervals are put in reverse order.
+    ///
+    /// # Panics
+    /// Panics if a mapped value is not comparable with itself, e.g., `f64::NAN`.
+    pub fn map_monotone(&self, f: impl Fn(T) -> T, increasing: bool) -> Self {
+        let mut intervals: Vec<Interval<T>> = self.intervals
+            .iter()
+            .map(|interval| interval.map_endpoints(&f, !increasing))
+            .collect();
+
+        // Keep the intervals sorted so that normalizing is cheap
+        if !increasing {
+            intervals.reverse();
+        }
+
+        Self::from_intervals(intervals)
+    }
+
+    /// Maps the endpoint values of the set by `f` into another type,
+    /// e.g., mapping `[0, 1] ∪ [2, 3]` by `|x| x * 1000` gives `[0, 1000] ∪ [2000, 3000]`.
+    /// Unbounded endpoints stay unbounded.
+    /// The function is expected to be increasing for the result to be meaningful,
+    /// but the result is normalized regardless, since the mapped intervals may be out of order or overlap.
+    /// Intervals whose mapped endpoints are out of order or not comparable are dropped.
+    pub fn map<U, F>(&self, f: F) -> IntervalSet<U> where U: PartialOrd + Clone, F: Fn(&T) -> U {
+        let map = |endpoint: &Endpoint<T>| match endpoint {
+            Endpoint::Open(value) => Endpoint::Open(f(value)),
+            Endpoint::Closed(value) => Endpoint::Closed(f(value)),
+            Endpoint::Unbounded => Endpoint::Unbounded,
+        };
+
+        let intervals = self.intervals
+            .iter()
+            .filter_map(|interval| Interval::new(map(interval.left()), map(interval.right())).ok());
+
+        IntervalSet::from_intervals(intervals)
+    }
+
+    /// Buffers the set by dilating every interval by the margin on both sides.
+    /// Intervals that come within twice the margin of each other are merged.
+    /// A negative margin erodes the set instead, dropping the intervals that vanish.
+    /// See [`Interval::dilate`].
+    pub fn buffer(&self, margin: T) -> Self where T: Add<Output = T> + Sub<Output = T> {
+        let intervals = self.intervals.iter().filter_map(|interval| interval.dilate(margin.clone()));
+
+        Self::from_intervals(intervals)
+    }
+
+    /// Gets each interval of this set paired with the length of its part covered by the other set.
+    /// The length is `None` if the covered part is unbounded.
+    /// The default value of `T` is regarded as zero.
+    pub fn contributions(&self, other: &Self) -> Vec<(Interval<T>, Option<T>)>
+        where T: Add<Output = T> + Sub<Output = T> + Default
+    {
+        self.intervals
+            .iter()
+            .map(|interval| {
+                let start = other.count_intervals_before(interval);
+                let covered = other.intervals[start..]
+                    .iter()
+                    .map_while(|other_interval| interval.intersection(other_interval))
+                    .try_fold(T::default(), |total, overlap| Some(total + overlap.width()?));
+
+                (interval.clone(), covered)
+            })
+            .collect()
+    }
+
+    /// Snaps the endpoints to the sorted allowed values so that only those values are boundaries.
+    /// A left endpoint rounds up to the least allowed value in the interval,
+    /// and a right endpoint rounds down to the greatest allowed value in the interval,
+    /// so the snapped endpoints are closed, and unbounded sides stay unbounded.
+    /// Intervals containing no allowed value, if bounded, are dropped.
+    pub fn snap_to_values(&self, allowed: &[T]) -> Self {
+        debug_assert!(
+            allowed.windows(2).all(|pair| pair[0] <= pair[1]),
+            "allowed values must be sorted"
+        );
+
+        let intervals = self.intervals
+            .iter()
+            .filter_map(|interval| {
+                let left = match interval.left() {
+                    Endpoint::Unbounded => Endpoint::Unbounded,
+                    _ => {
+                        let index = allowed.partition_point(|value| interval.is_after(value));
+                        Endpoint::Closed(allowed.get(index)?.clone())
+                    }
+                };
+                let right = match interval.right() {
+                    Endpoint::Unbounded => Endpoint::Unbounded,
+                    _ => {
+                        // Count the allowed values not lying to the right of the interval
+                        let count = allowed.partition_point(|value| {
+                            interval.is_after(value) || interval.contains(value)
+                        });
+                        Endpoint::Closed(allowed.get(count.checked_sub(1)?)?.clone())
+                    }
+                };
+
+                Interval::new(left, right).ok()
+            });
+
+        Self::from_intervals(intervals)
+    }
+
+    /// Gets the union of the two sets.
     pub fn union(&self, other: &Self) -> Self {
-        todo!()
+        Self {
+            intervals: self.merge_sorted_lists(other).into_iter().coalesce_intervals().collect(),
+        }
+    }
+
+    /// Gets the union of the two sets,
+    /// where the policy decides which intervals are merged.
+    /// Under [`MergePolicy::Strict`], touching intervals may be kept apart,
+    /// so the result is not an interval set.
+    pub fn union_with(&self, other: &Self, policy: MergePolicy) -> MergedIntervals<T> {
+        MergedIntervals::from_sorted(self.merge_sorted_lists(other), policy)
+    }
+
+    /// Merges the intervals of the two sets into a single list sorted by their left endpoints,
+    /// without merging any intervals.
+    fn merge_sorted_lists(&self, other: &Self) -> Vec<Interval<T>> {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + other.intervals.len());
+
+        // Merge the two sorted lists of intervals
+        let mut i = 0;
+        let mut j = 0;
+        while i < self.intervals.len() && j < other.intervals.len() {
+            if self.intervals[i].cmp_left_endpoints(&other.intervals[j]) == Ordering::Greater {
+                intervals.push(other.intervals[j].clone());
+                j += 1;
+            } else {
+                intervals.push(self.intervals[i].clone());
+                i += 1;
+            }
+        }
+        intervals.extend_from_slice(&self.intervals[i..]);
+        intervals.extend_from_slice(&other.intervals[j..]);
+
+        intervals
+    }
+
+    /// Merges the sorted and disjoint intervals into the set
+    /// in a single linear pass without sorting.
+    /// The intervals must be sorted by their left endpoints and separated from each other.
+    pub fn merge_sorted_slice(&mut self, sorted_disjoint: &[Interval<T>]) {
+        let other = Self { intervals: sorted_disjoint.to_vec() };
+        debug_assert!(other.is_normalized(), "intervals must be sorted and disjoint");
+
+        *self = self.union(&other);
+    }
+
+    /// Inserts the interval into the set,
+    /// merging it with every interval it is not separated from,
+    /// e.g., inserting `[4, 6]` into `[0, 2] ∪ [5, 8]` gives `[0, 2] ∪ [4, 8]`.
+    /// This takes O(log n) time to locate the neighbors plus the time to shift the rest.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Count the intervals separated from the new one on its left
+        let start = self.intervals.partition_point(|other| {
+            other.is_separated_from(&interval) && other.cmp_left_endpoints(&interval) == Ordering::Less
+        });
+
+        // The following intervals up to the first separated one are merged
+        let end = start + self.intervals[start..].partition_point(|other| !other.is_separated_from(&interval));
+
+        let merged = self.intervals[start..end]
+            .iter()
+            .fold(interval, |merged, other| merged.merge_unchecked(other));
+        self.intervals.splice(start..end, [merged]);
+    }
+
+    /// Adds the single point to the set,
+    /// merging it into the intervals it touches,
+    /// e.g., inserting `1` into `[0, 1)` gives `[0, 1]`.
+    /// A value not equal to itself, such as `NaN`, is ignored.
+    pub fn insert_point(&mut self, value: T) {
+        // A point already in the set changes nothing
+        if self.locate(&value).is_ok() {
+            return;
+        }
+
+        if let Ok(point) = Interval::closed(value.clone(), value) {
+            self.insert(point);
+        }
+    }
+
+    /// Removes the single point from the set,
+    /// splitting the interval containing it if the point is interior,
+    /// e.g., removing `4` from `[0, 10]` gives `[0, 4) ∪ (4, 10]`.
+    /// Removing a point not in the set does nothing.
+    pub fn remove_point(&mut self, value: &T) {
+        let Ok(index) = self.locate(value) else {
+            return;
+        };
+        let interval = &self.intervals[index];
+
+        // Either piece is empty if the value is a closed endpoint
+        let below = Interval::new(interval.left().clone(), Endpoint::Open(value.clone()));
+        let above = Interval::new(Endpoint::Open(value.clone()), interval.right().clone());
+        self.intervals.splice(index..=index, below.into_iter().chain(above));
     }
 
+    /// Gets the union of many sets.
+    /// All intervals are sorted and merged in a single pass
+    /// instead of unioning the sets pairwise.
+    pub fn union_all<I: IntoIterator<Item = IntervalSet<T>>>(sets: I) -> Self {
+        let mut set = Self {
+            intervals: sets
+                .into_iter()
+                .flat_map(|set| set.intervals)
+                .collect(),
+        };
+        set.normalize();
+
+        set
+    }
+
+    /// Removes the points in any of the subtrahends from the base set.
+    /// The subtrahends are merged in a single pass and subtracted at once
+    /// instead of rebuilding the result for each of them.
+    pub fn subtract_all(base: IntervalSet<T>, subtrahends: &[IntervalSet<T>]) -> Self {
+        if subtrahends.is_empty() {
+            return base;
+        }
+
+        let removed = Self::union_all(subtrahends.iter().cloned());
+
+        base.difference(&removed)
+    }
+
+    /// Gets the intersection of many sets.
+    /// The sets are swept simultaneously
+    /// instead of being intersected pairwise.
+    /// The intersection of no sets is the universe.
+    pub fn intersection_all<I: IntoIterator<Item = IntervalSet<T>>>(sets: I) -> Self {
+        let sets: Vec<IntervalSet<T>> = sets.into_iter().collect();
+        if sets.is_empty() {
+            return Self::from(Interval::universe());
+        }
+
+        let mut intervals = Vec::new();
+
+        // Index of the current interval in each set
+        let mut indices = vec![0; sets.len()];
+
+        loop {
+            // Get the current interval of each set
+            // The sweep ends once any set runs out of intervals
+            let current: Option<Vec<&Interval<T>>> = sets
+                .iter()
+                .zip(&indices)
+                .map(|(set, &index)| set.intervals.get(index))
+                .collect();
+            let Some(current) = current else {
+                break;
+            };
+
+            // Intersect the current intervals
+            let intersection = current
+                .iter()
+                .try_fold(Interval::universe(), |intersection, interval| {
+                    intersection.intersection(interval)
+                });
+            if let Some(interval) = intersection {
+                intervals.push(interval);
+            }
+
+            // Move on from the interval that ends first
+            let mut first = 0;
+            for k in 1..current.len() {
+                if current[k].cmp_right_endpoints(current[first]) == Ordering::Less {
+                    first = k;
+                }
+            }
+            indices[first] += 1;
+        }
+
+        // The pieces are already sorted and separated
+        Self { intervals }
+    }
+
+    /// Gets the intersection of the two sets.
     pub fn intersection(&self, other: &Self) -> Self {
-        todo!()
+        let mut intervals = Vec::new();
+
+        // Sweep through both sets simultaneously
+        let mut i = 0;
+        let mut j = 0;
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let this_interval = &self.intervals[i];
+            let other_interval = &other.intervals[j];
+
+            if let Some(interval) = this_interval.intersection(other_interval) {
+                intervals.push(interval);
+            }
+
+            // Move on from the interval that ends first
+            if this_interval.cmp_right_endpoints(other_interval) == Ordering::Less {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // The pieces are already sorted and separated
+        Self { intervals }
+    }
+
+    /// Splits the set at the value into
+    /// the part strictly below the value and the part at or above the value.
+    /// For example, splitting `[0, 10]` at `4` gives `[0, 4)` and `[4, 10]`.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let below = self.intersection(&Self::unbounded_open(value.clone()));
+        let above = self.intersection(&Self::closed_unbounded(value));
+
+        (below, above)
+    }
+
+    /// Trims the set to the points above the value,
+    /// including the value itself if `inclusive` is true.
+    /// This takes O(log n + k) time, where k is the number of intervals kept.
+    pub fn restrict_above(&self, value: T, inclusive: bool) -> Self {
+        let half_line = if inclusive {
+            Interval::closed_unbounded(value)
+        } else {
+            Interval::open_unbounded(value)
+        };
+
+        // Skip the intervals lying entirely below the half-line
+        let start = self.intervals.partition_point(|interval| {
+            !interval.overlaps(&half_line)
+        });
+
+        // Only the first interval kept may need trimming
+        let mut intervals = Vec::with_capacity(self.intervals.len() - start);
+        if let Some(first) = self.intervals.get(start) {
+            intervals.extend(first.intersection(&half_line));
+            intervals.extend_from_slice(&self.intervals[start + 1..]);
+        }
+
+        Self { intervals }
+    }
+
+    /// Trims the set to the points below the value,
+    /// including the value itself if `inclusive` is true.
+    /// This takes O(log n + k) time, where k is the number of intervals kept.
+    pub fn restrict_below(&self, value: T, inclusive: bool) -> Self {
+        let half_line = if inclusive {
+            Interval::unbounded_closed(value)
+        } else {
+            Interval::unbounded_open(value)
+        };
+
+        // Skip the intervals lying entirely above the half-line
+        let end = self.intervals.partition_point(|interval| {
+            interval.overlaps(&half_line)
+        });
+
+        // Only the last interval kept may need trimming
+        let mut intervals = Vec::with_capacity(end);
+        if let Some((last, rest)) = self.intervals[..end].split_last() {
+            intervals.extend_from_slice(rest);
+            intervals.extend(last.intersection(&half_line));
+        }
+
+        Self { intervals }
+    }
+
+    /// Checks if the two sets share no common point.
+    /// Unlike computing the intersection,
+    /// the sweep stops as soon as an overlap is found.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let mut i = 0;
+        let mut j = 0;
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let this_interval = &self.intervals[i];
+            let other_interval = &other.intervals[j];
+
+            if this_interval.overlaps(other_interval) {
+                return false;
+            }
+
+            // Move on from the interval that ends first
+            if this_interval.cmp_right_endpoints(other_interval) == Ordering::Less {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        true
+    }
+
+    /// Gets the points in this set that are not in the other set.
+    pub(crate) fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Gets the points in exactly one of the two sets,
+    /// e.g., the symmetric difference of `[0, 2]` and `[1, 3]` is `[0, 1) ∪ (2, 3]`.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.union(other).difference(&self.intersection(other))
+    }
+
+    /// Gets the complement of the set relative to another set,
+    /// i.e., the points in `universe` that are not in this set,
+    /// e.g., the team's availability left after one person's bookings.
+    pub fn complement_in(&self, universe: &Self) -> Self {
+        universe.difference(self)
+    }
+
+    /// Gets the complement of the set with respect to the universe.
+    pub fn complement(&self) -> Self {
+        let mut intervals = Vec::new();
+
+        // Left endpoint of the gap to be pushed next
+        // It is none if the previous interval is unbounded on the right
+        let mut gap_left = Some(Endpoint::Unbounded);
+
+        for interval in &self.intervals {
+            // The gap ends where the interval starts, with the openness flipped
+            let gap_right = match interval.left() {
+                Endpoint::Open(low) => Some(Endpoint::Closed(low.clone())),
+                Endpoint::Closed(low) => Some(Endpoint::Open(low.clone())),
+                Endpoint::Unbounded => None,
+            };
+
+            if let (Some(left), Some(right)) = (gap_left, gap_right) {
+                // It is safe to unwrap since the intervals are separated
+                intervals.push(Interval::new(left, right).unwrap());
+            }
+
+            // The next gap starts where the interval ends, with the openness flipped
+            gap_left = match interval.right() {
+                Endpoint::Open(high) => Some(Endpoint::Closed(high.clone())),
+                Endpoint::Closed(high) => Some(Endpoint::Open(high.clone())),
+                Endpoint::Unbounded => None,
+            };
+        }
+
+        if let Some(left) = gap_left {
+            // It is safe to unwrap since the right endpoint is unbounded
+            intervals.push(Interval::new(left, Endpoint::Unbounded).unwrap());
+        }
+
+        Self { intervals }
+    }
+
+    /// Checks if the set satisfies its invariant, i.e.,
+    /// the intervals are sorted by their left endpoints, and
+    /// every two of them are separated.
+    pub fn is_normalized(&self) -> bool {
+        // It suffices to check the neighboring intervals
+        self.intervals
+            .windows(2)
+            .all(|pair| {
+                pair[0].cmp_left_endpoints(&pair[1]) == Ordering::Less &&
+                    pair[0].is_separated_from(&pair[1])
+            })
+    }
+
+    /// Checks the invariant of the set in detail, i.e.,
+    /// every interval is valid, and
+    /// the intervals are sorted and separated.
+    /// Returns an error pointing at the first violation.
+    pub fn validate(&self) -> IntervalSetResult<()> {
+        for (index, interval) in self.intervals.iter().enumerate() {
+            if Interval::new(interval.left().clone(), interval.right().clone()).is_err() {
+                return Err(IntervalSetError::InvalidIntervalAt { index });
+            }
+        }
+
+        for (index, pair) in self.intervals.windows(2).enumerate() {
+            if
+                pair[0].cmp_left_endpoints(&pair[1]) != Ordering::Less ||
+                !pair[0].is_separated_from(&pair[1])
+            {
+                return Err(IntervalSetError::UnnormalizedIntervalAt { index });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Restores the invariant of the set, i.e.,
+    /// the intervals are sorted by their left endpoints, and
+    /// every two of them are separated.
+    /// Intervals that overlap or touch are merged.
+    /// This takes O(n) time if the invariant already holds,
+    /// which is the common case, and O(n log n) time otherwise.
+    pub fn normalize(&mut self) {
+        // Skip sorting and merging if the invariant already holds
+        if self.is_normalized() {
+            return;
+        }
+
+        self.intervals.sort_by(|a, b| a.cmp_left_endpoints(b));
+        self.intervals = self.intervals.drain(..).coalesce_intervals().collect();
+    }
+
+    /// Gets the normalized set,
+    /// which borrows the set itself without copying if the invariant already holds.
+    fn normalized(&self) -> Cow<'_, Self> {
+        if self.is_normalized() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut set = self.clone();
+        set.normalize();
+
+        Cow::Owned(set)
     }
 }
 
@@ -93,10 +1095,1614 @@ impl<T: PartialOrd + Clone> From<Interval<T>> for IntervalSet<T> {
     }
 }
 
-impl<T: PartialOrd + Clone> BitAnd for IntervalSet<T> {
+/// Two sets are equal if they contain exactly the same points.
+///
+/// Normalized sets are compared interval by interval,
+/// which is correct only because the invariant makes the representation unique.
+/// Sets that are not normalized yet are normalized before comparing.
+impl<T: PartialOrd + Clone> PartialEq for IntervalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized().intervals == other.normalized().intervals
+    }
+}
+
+/// Formats the set as its intervals separated by `∪`, e.g., `[0, 1) ∪ (2, 3]`.
+/// The empty set is formatted as `∅`.
+impl<T: PartialOrd + Clone + Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.intervals.is_empty() {
+            return write!(f, "∅");
+        }
+
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ∪ ")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: PartialOrd + Clone> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T: PartialOrd + Clone> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+impl<T: PartialOrd + Clone> Not for IntervalSet<T> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: PartialOrd + Clone> BitAnd for IntervalSet<T> {
     type Output = Self;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        todo!()
+        self.intersection(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitAnd<&IntervalSet<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitand(self, rhs: &IntervalSet<T>) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitAnd for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitOr for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitOr<&IntervalSet<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitor(self, rhs: &IntervalSet<T>) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitOr for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitXor for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitXor<&IntervalSet<T>> for IntervalSet<T> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: &IntervalSet<T>) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitXor for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use super::*;
+    use crate::sorting::TestRng;
+
+    fn build_set(intervals: Vec<Interval<i32>>) -> IntervalSet<i32> {
+        IntervalSet::from_intervals(intervals)
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed(5, 6).unwrap(),
+                Interval::<i32>::open(0, 2).unwrap(),
+                Interval::<i32>::closed_open(2, 3).unwrap(),
+                Interval::<i32>::unbounded_open(-5)
+            ],
+        };
+        set.normalize();
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::<i32>::unbounded_open(-5),
+                Interval::<i32>::open(0, 3).unwrap(),
+                Interval::<i32>::closed(5, 6).unwrap()
+            ]
+        );
+
+        let mut set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed(8, 9).unwrap(),
+                Interval::<i32>::open_unbounded(12),
+                Interval::<i32>::closed_open(4, 6).unwrap(),
+                Interval::<i32>::closed(1, 2).unwrap(),
+                Interval::<i32>::open(9, 10).unwrap(),
+                Interval::<i32>::closed(14, 15).unwrap(),
+                Interval::<i32>::open(2, 5).unwrap(),
+                Interval::<i32>::closed(0, 1).unwrap()
+            ],
+        };
+        set.normalize();
+        assert!(set.is_normalized());
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::<i32>::closed_open(0, 6).unwrap(),
+                Interval::<i32>::closed_open(8, 10).unwrap(),
+                Interval::<i32>::open_unbounded(12)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_policy() {
+        let intervals = vec![Interval::<i32>::closed(1, 2).unwrap(), Interval::<i32>::open(0, 1).unwrap()];
+
+        let merged = MergedIntervals::new(intervals.clone(), MergePolicy::TopologicalClosure);
+        assert_eq!(merged.intervals(), [Interval::open_closed(0, 2).unwrap()]);
+
+        let merged = MergedIntervals::new(intervals, MergePolicy::Strict);
+        assert_eq!(merged.policy(), MergePolicy::Strict);
+        assert_eq!(merged.intervals(), [Interval::open(0, 1).unwrap(), Interval::closed(1, 2).unwrap()]);
+
+        // Converting into a set merges the touching intervals
+        let set = merged.into_set();
+        assert!(set.is_normalized());
+        assert_eq!(set.intervals, vec![Interval::open_closed(0, 2).unwrap()]);
+        assert!(set.contains_interval(&Interval::open_closed(0, 2).unwrap()));
+
+        let a = build_set(vec![Interval::open(0, 1).unwrap(), Interval::closed(5, 6).unwrap()]);
+        let b = build_set(vec![Interval::closed(1, 2).unwrap(), Interval::closed(6, 7).unwrap()]);
+        assert_eq!(
+            a.union_with(&b, MergePolicy::TopologicalClosure).intervals(),
+            a.union(&b).intervals
+        );
+        assert_eq!(
+            a.union_with(&b, MergePolicy::Strict).intervals(),
+            [
+                Interval::open(0, 1).unwrap(),
+                Interval::closed(1, 2).unwrap(),
+                Interval::closed(5, 7).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_from_intervals() {
+        let set = IntervalSet::try_from_intervals(vec![
+            Interval::<i32>::closed(5, 7).unwrap(),
+            Interval::<i32>::closed_open(0, 2).unwrap(),
+            Interval::<i32>::open(1, 3).unwrap(),
+            Interval::<i32>::closed(6, 9).unwrap()
+        ]);
+        assert!(set.is_ok());
+        assert_eq!(
+            set.unwrap().intervals,
+            vec![Interval::closed_open(0, 3).unwrap(), Interval::closed(5, 9).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_from_intervals() {
+        let set = IntervalSet::from_intervals([
+            Interval::<i32>::closed(0, 2).unwrap(),
+            Interval::<i32>::closed(1, 3).unwrap(),
+            Interval::<i32>::closed(10, 11).unwrap()
+        ]);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::closed(0, 3).unwrap(), Interval::closed(10, 11).unwrap()]
+        );
+
+        // Any order of the same intervals gives the same set
+        let intervals = vec![
+            Interval::<i32>::closed(10, 11).unwrap(),
+            Interval::<i32>::open(2, 5).unwrap(),
+            Interval::<i32>::closed(0, 1).unwrap(),
+            Interval::<i32>::closed_open(1, 3).unwrap(),
+            Interval::<i32>::open_unbounded(12),
+            Interval::<i32>::closed(4, 6).unwrap()
+        ];
+        let expected = vec![
+            Interval::closed(0, 6).unwrap(),
+            Interval::closed(10, 11).unwrap(),
+            Interval::open_unbounded(12)
+        ];
+        let mut rng = Lcg::new(7);
+        for _ in 0..50 {
+            let mut shuffled = intervals.clone();
+            for i in (1..shuffled.len()).rev() {
+                shuffled.swap(i, rng.range(0, (i as i32) + 1) as usize);
+            }
+
+            let set = IntervalSet::from_intervals(shuffled);
+            assert!(set.is_normalized());
+            assert_eq!(set.intervals, expected);
+        }
+
+        assert!(IntervalSet::<i32>::from_intervals([]).is_empty());
+        assert!(IntervalSet::<i32>::from_intervals(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_with_comparator() {
+        let set = IntervalSet::<f64>::with_comparator(
+            vec![
+                Interval::closed(2.0, 3.0).unwrap(),
+                Interval::open(0.5, 1.5).unwrap(),
+                Interval::closed_open(1.0, 2.0).unwrap()
+            ],
+            f64::total_cmp
+        );
+        assert_eq!(set.unwrap().intervals, vec![Interval::open_closed(0.5, 3.0).unwrap()]);
+
+        // NaN is rejected with an error on the comparator path
+        let interval = Interval::new_by(Endpoint::Closed(0.0), Endpoint::Open(f64::NAN), f64::total_cmp);
+        assert_eq!(interval, Err(IntervalSetError::NonComparableEndpoint));
+
+        // An interval the comparator disagrees on is rejected from the set
+        let set = IntervalSet::<f64>::with_comparator(
+            vec![Interval::closed(1.0, 2.0).unwrap(), Interval::closed(-0.0, 0.0).unwrap()],
+            f64::total_cmp
+        );
+        assert_eq!(set, Err(IntervalSetError::InconsistentComparator));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::unbounded_open(0),
+                Interval::<i32>::closed(1, 4).unwrap(),
+                Interval::<i32>::closed_unbounded(6)
+            ],
+        };
+        let b = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed(-2, 2).unwrap(),
+                Interval::<i32>::closed(4, 7).unwrap()
+            ],
+        };
+        assert_eq!(
+            a.intersection(&b).intervals,
+            vec![
+                Interval::<i32>::closed_open(-2, 0).unwrap(),
+                Interval::<i32>::closed(1, 2).unwrap(),
+                Interval::<i32>::closed(4, 4).unwrap(),
+                Interval::<i32>::closed(6, 7).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        let a = build_set(vec![Interval::closed(0, 2).unwrap(), Interval::open(5, 6).unwrap()]);
+        let b = build_set(vec![Interval::closed_open(1, 3).unwrap(), Interval::closed(6, 7).unwrap()]);
+        assert_eq!(
+            a.union(&b).intervals,
+            vec![Interval::closed_open(0, 3).unwrap(), Interval::open_closed(5, 7).unwrap()]
+        );
+    }
+
+    #[test]
+    fn union_keeps_separated_intervals_apart() {
+        let a = build_set(vec![Interval::closed(0, 2).unwrap()]);
+        let b = build_set(vec![Interval::closed_open(1, 3).unwrap()]);
+        assert_eq!(a.union(&b).intervals, vec![Interval::closed_open(0, 3).unwrap()]);
+
+        // The point 1 is missing from both sets
+        let a = build_set(vec![Interval::open(0, 1).unwrap()]);
+        let b = build_set(vec![Interval::open(1, 2).unwrap()]);
+        let union = a.union(&b);
+        assert_eq!(union.intervals, vec![Interval::open(0, 1).unwrap(), Interval::open(1, 2).unwrap()]);
+        assert!(union.is_normalized());
+
+        // Touching at a closed endpoint merges
+        let a = build_set(vec![Interval::open_closed(0, 1).unwrap()]);
+        let union = a.union(&b);
+        assert_eq!(union.intervals, vec![Interval::open(0, 2).unwrap()]);
+    }
+
+    #[test]
+    fn union_of_unbounded_intervals() {
+        let a = build_set(vec![Interval::unbounded_open(0)]);
+        let b = build_set(vec![Interval::closed_unbounded(0)]);
+        assert_eq!(a.union(&b).intervals, vec![Interval::universe()]);
+        assert_eq!(b.union(&a).intervals, vec![Interval::universe()]);
+
+        // The point 0 is missing
+        let b = build_set(vec![Interval::open_unbounded(0)]);
+        assert_eq!(
+            a.union(&b).intervals,
+            vec![Interval::unbounded_open(0), Interval::open_unbounded(0)]
+        );
+
+        // Bounded intervals are absorbed by the unbounded ones
+        let c = build_set(vec![Interval::closed(-5, -3).unwrap(), Interval::closed(0, 0).unwrap(), Interval::closed(3, 5).unwrap()]);
+        assert_eq!(a.union(&b).union(&c).intervals, vec![Interval::universe()]);
+    }
+
+    #[test]
+    fn operators_match_named_methods() {
+        let a = build_set(vec![Interval::closed(0, 2).unwrap(), Interval::open(5, 8).unwrap()]);
+        let b = build_set(vec![Interval::closed_open(1, 3).unwrap(), Interval::closed(6, 7).unwrap()]);
+        let c = build_set(vec![Interval::closed(2, 6).unwrap()]);
+
+        assert_eq!((a.clone() & b.clone()).intervals, a.intersection(&b).intervals);
+        assert_eq!((a.clone() & &b).intervals, a.intersection(&b).intervals);
+        assert_eq!((&a & &b).intervals, a.intersection(&b).intervals);
+
+        assert_eq!((a.clone() | b.clone()).intervals, a.union(&b).intervals);
+        assert_eq!((a.clone() | &b).intervals, a.union(&b).intervals);
+        assert_eq!((&a | &b).intervals, a.union(&b).intervals);
+
+        // Chaining without cloning the right-hand operands
+        assert_eq!((a.clone() | &b & &c).intervals, a.union(&b.intersection(&c)).intervals);
+        assert_eq!(((&a | &b) & &c).intervals, a.union(&b).intersection(&c).intervals);
+    }
+
+    #[test]
+    fn test_union_all_and_intersection_all() {
+        let sets = vec![
+            build_set(vec![Interval::closed(0, 4).unwrap(), Interval::closed(10, 12).unwrap()]),
+            build_set(vec![Interval::closed_open(2, 6).unwrap(), Interval::open(11, 20).unwrap()]),
+            build_set(vec![Interval::unbounded_closed(3), Interval::closed_unbounded(11)]),
+            build_set(vec![Interval::open(1, 8).unwrap(), Interval::closed(11, 15).unwrap()]),
+            build_set(vec![Interval::closed(3, 3).unwrap(), Interval::open(2, 5).unwrap(), Interval::open(9, 13).unwrap()])
+        ];
+
+        let union = IntervalSet::union_all(sets.clone());
+        assert_eq!(
+            union.intervals,
+            vec![Interval::unbounded_open(8), Interval::open_unbounded(9)]
+        );
+        let folded = sets[1..].iter().fold(sets[0].clone(), |union, set| union.union(set));
+        assert_eq!(union.intervals, folded.intervals);
+
+        let intersection = IntervalSet::intersection_all(sets.clone());
+        assert_eq!(
+            intersection.intervals,
+            vec![Interval::open_closed(2, 3).unwrap(), Interval::open_closed(11, 12).unwrap()]
+        );
+        let folded = sets[1..].iter().fold(sets[0].clone(), |intersection, set| intersection.intersection(set));
+        assert_eq!(intersection.intervals, folded.intervals);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let set = build_set(vec![Interval::closed(0, 10).unwrap()]);
+        let (below, above) = set.split_at(4);
+        assert_eq!(below.intervals, vec![Interval::closed_open(0, 4).unwrap()]);
+        assert_eq!(above.intervals, vec![Interval::closed(4, 10).unwrap()]);
+
+        // Inside a gap
+        let set = build_set(vec![
+            Interval::unbounded_closed(0),
+            Interval::open(2, 3).unwrap(),
+            Interval::closed(6, 7).unwrap()
+        ]);
+        let (below, above) = set.split_at(5);
+        assert_eq!(below.intervals, vec![Interval::unbounded_closed(0), Interval::open(2, 3).unwrap()]);
+        assert_eq!(above.intervals, vec![Interval::closed(6, 7).unwrap()]);
+
+        // At a closed boundary
+        let (below, above) = set.split_at(6);
+        assert_eq!(below.intervals, vec![Interval::unbounded_closed(0), Interval::open(2, 3).unwrap()]);
+        assert_eq!(above.intervals, vec![Interval::closed(6, 7).unwrap()]);
+
+        let (below, above) = set.split_at(7);
+        assert_eq!(below.intervals.last(), Some(&Interval::closed_open(6, 7).unwrap()));
+        assert_eq!(above.intervals, vec![Interval::closed(7, 7).unwrap()]);
+    }
+
+    #[test]
+    fn test_is_disjoint() {
+        // Touching but open
+        let a = build_set(vec![Interval::closed_open(0, 1).unwrap(), Interval::open(5, 6).unwrap()]);
+        let b = build_set(vec![Interval::closed(1, 2).unwrap(), Interval::closed_unbounded(6)]);
+        assert!(a.is_disjoint(&b));
+        assert!(b.is_disjoint(&a));
+
+        // Overlapping
+        let a = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::open(5, 6).unwrap()]);
+        let b = build_set(vec![Interval::open(2, 3).unwrap(), Interval::open(5, 6).unwrap()]);
+        assert!(!a.is_disjoint(&b));
+
+        // Sharing a single point
+        let a = build_set(vec![Interval::closed(0, 1).unwrap()]);
+        let b = build_set(vec![Interval::closed(1, 2).unwrap()]);
+        assert!(!a.is_disjoint(&b));
+
+        let a = build_set(vec![Interval::universe()]);
+        let b = build_set(vec![]);
+        assert!(a.is_disjoint(&b));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = build_set(vec![
+            Interval::open(0, 1).unwrap(),
+            Interval::closed(2, 3).unwrap(),
+            Interval::open_unbounded(5)
+        ]);
+        assert!(!set.contains(&0));
+        assert!(!set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+        assert!(!set.contains(&5));
+        assert!(set.contains(&1000));
+
+        let set = build_set(vec![Interval::unbounded_open(5)]);
+        assert!(set.contains(&i32::MIN));
+        assert!(set.contains(&4));
+        assert!(!set.contains(&5));
+
+        let set = build_set(vec![]);
+        assert!(!set.contains(&0));
+    }
+
+    #[test]
+    fn test_iter() {
+        let set = IntervalSet::open(5, 7)
+            .unwrap()
+            .union(&IntervalSet::closed(0, 2).unwrap())
+            .union(&IntervalSet::closed_open(1, 3).unwrap());
+        let expected = vec![Interval::closed_open(0, 3).unwrap(), Interval::open(5, 7).unwrap()];
+
+        assert_eq!(set.iter().count(), 2);
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), expected);
+
+        let mut borrowed = Vec::new();
+        for interval in &set {
+            borrowed.push(interval.to_string());
+        }
+        assert_eq!(borrowed, vec!["[0, 3)", "(5, 7)"]);
+
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = build_set(vec![Interval::closed(0, 2).unwrap(), Interval::closed(5, 8).unwrap()]);
+        set.insert(Interval::closed(4, 6).unwrap());
+        assert_eq!(set.intervals, vec![Interval::closed(0, 2).unwrap(), Interval::closed(4, 8).unwrap()]);
+
+        // Bridging two intervals merges all three
+        set.insert(Interval::open(2, 4).unwrap());
+        assert_eq!(set.intervals, vec![Interval::closed(0, 8).unwrap()]);
+
+        // Separated intervals are kept apart
+        set.insert(Interval::open(8, 9).unwrap());
+        set.insert(Interval::open(-2, -1).unwrap());
+        assert_eq!(
+            set.intervals,
+            vec![Interval::open(-2, -1).unwrap(), Interval::closed_open(0, 9).unwrap()]
+        );
+
+        set.insert(Interval::unbounded_closed(-1));
+        assert_eq!(
+            set.intervals,
+            vec![Interval::unbounded_closed(-1), Interval::closed_open(0, 9).unwrap()]
+        );
+        set.insert(Interval::open_unbounded(-1));
+        assert_eq!(set.intervals, vec![Interval::universe()]);
+    }
+
+    #[test]
+    fn insert_matches_repeated_union() {
+        let mut rng = Lcg::new(7);
+
+        for _ in 0..2000 {
+            let mut set = build_set(vec![]);
+            let mut reference = build_set(vec![]);
+            for interval in rng.set().intervals.into_iter().chain(rng.set().intervals) {
+                reference = reference.union(&IntervalSet::from(interval.clone()));
+                set.insert(interval);
+                assert!(set.is_normalized());
+            }
+            assert_eq!(set.intervals, reference.intervals);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = IntervalSet::<i32>::empty();
+        assert!(empty.is_empty());
+        assert!(!IntervalSet::closed(0, 0).unwrap().is_empty());
+
+        let set = build_set(vec![Interval::closed(0, 2).unwrap(), Interval::open_unbounded(5)]);
+        assert_eq!(set.union(&empty).intervals, set.intervals);
+        assert_eq!(empty.union(&set).intervals, set.intervals);
+        assert!(set.intersection(&empty).is_empty());
+        assert!(empty.intersection(&set).is_empty());
+
+        // Disjoint sets intersect in the empty set
+        assert!(set.intersection(&IntervalSet::open(2, 5).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_eq() {
+        let a = IntervalSet::closed(0, 1).unwrap().union(&IntervalSet::closed(1, 2).unwrap());
+        let mut b = IntervalSet::empty();
+        b.insert(Interval::closed(1, 2).unwrap());
+        b.insert(Interval::closed(0, 1).unwrap());
+        assert_eq!(a, b);
+        assert_eq!(a, IntervalSet::closed(0, 2).unwrap());
+
+        assert_ne!(IntervalSet::closed_open(0, 1).unwrap(), IntervalSet::closed(0, 1).unwrap());
+        assert_ne!(a, IntervalSet::empty());
+        assert_eq!(IntervalSet::<i32>::empty(), IntervalSet::empty());
+
+        // Touching intervals kept apart cover the same points once converted into a set
+        let strict = IntervalSet::open(0, 1)
+            .unwrap()
+            .union_with(&IntervalSet::closed(1, 2).unwrap(), MergePolicy::Strict);
+        assert_eq!(strict.intervals().len(), 2);
+        assert_eq!(strict.into_set(), IntervalSet::open_closed(0, 2).unwrap());
+    }
+
+    #[test]
+    fn test_measure() {
+        let set = build_set(vec![
+            Interval::closed(0, 5).unwrap(),
+            Interval::closed(7, 7).unwrap(),
+            Interval::open(8, 10).unwrap()
+        ]);
+        assert_eq!(set.measure(), Some(7));
+        assert_eq!(build_set(vec![]).measure(), Some(0));
+
+        let set = IntervalSet::closed(0.5, 1.0).unwrap().union(&IntervalSet::open(2.0, 4.25).unwrap());
+        assert_eq!(set.measure(), Some(2.75));
+
+        let set = build_set(vec![Interval::closed(0, 5).unwrap(), Interval::at_least(10)]);
+        assert_eq!(set.measure(), None);
+        assert_eq!(IntervalSet::<f64>::unbounded_closed(1.0).measure(), None);
+    }
+
+    #[test]
+    fn test_is_subset() {
+        let a = IntervalSet::closed(1, 2).unwrap();
+        let b = IntervalSet::closed(0, 3).unwrap();
+        assert!(a.is_subset(&b));
+        assert!(!b.is_subset(&a));
+        assert!(!IntervalSet::closed(0, 4).unwrap().is_subset(&b));
+        assert!(b.is_subset(&b));
+
+        // Openness matters at the boundary
+        assert!(!IntervalSet::closed(0, 3).unwrap().is_subset(&IntervalSet::closed_open(0, 3).unwrap()));
+        assert!(IntervalSet::open(0, 3).unwrap().is_subset(&IntervalSet::closed_open(0, 3).unwrap()));
+
+        // Each interval must be covered by a single interval of the other set
+        let a = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(5, 6).unwrap()]);
+        let b = build_set(vec![Interval::closed(-1, 2).unwrap(), Interval::closed(4, 7).unwrap()]);
+        assert!(a.is_subset(&b));
+        let b = build_set(vec![Interval::closed_open(0, 3).unwrap(), Interval::open(3, 7).unwrap()]);
+        assert!(a.is_subset(&b));
+        assert!(!IntervalSet::closed(2, 4).unwrap().is_subset(&b));
+
+        // The empty set and the universe
+        let empty = IntervalSet::empty();
+        let universe = IntervalSet::from(Interval::universe());
+        assert!(empty.is_subset(&a));
+        assert!(empty.is_subset(&empty));
+        assert!(!a.is_subset(&empty));
+        assert!(a.is_subset(&universe));
+        assert!(universe.is_subset(&universe));
+        assert!(!universe.is_subset(&a));
+
+        // Unbounded intervals
+        let a = build_set(vec![Interval::unbounded_open(-5), Interval::closed_unbounded(5)]);
+        let b = build_set(vec![Interval::unbounded_closed(0), Interval::open_unbounded(4)]);
+        assert!(a.is_subset(&b));
+        assert!(!b.is_subset(&a));
+    }
+
+    #[test]
+    fn test_contains_interval() {
+        let set = build_set(vec![Interval::closed_open(0, 3).unwrap(), Interval::open_unbounded(5)]);
+        assert!(set.contains_interval(&Interval::closed(0, 2).unwrap()));
+        assert!(set.contains_interval(&Interval::closed_open(0, 3).unwrap()));
+        assert!(!set.contains_interval(&Interval::closed(0, 3).unwrap()));
+        assert!(!set.contains_interval(&Interval::closed(-1, 1).unwrap()));
+        assert!(set.contains_interval(&Interval::closed_unbounded(6)));
+        assert!(!set.contains_interval(&Interval::closed_unbounded(5)));
+        assert!(!set.contains_interval(&Interval::closed(2, 6).unwrap()));
+        assert!(!build_set(vec![]).contains_interval(&Interval::closed(0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_complement() {
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed_open(0, 1).unwrap(),
+                Interval::<i32>::open_closed(2, 3).unwrap()
+            ],
+        };
+        assert_eq!(
+            set.complement().intervals,
+            vec![
+                Interval::<i32>::unbounded_open(0),
+                Interval::<i32>::closed(1, 2).unwrap(),
+                Interval::<i32>::open_unbounded(3)
+            ]
+        );
+
+        let set = IntervalSet {
+            intervals: vec![Interval::<i32>::unbounded_open(0), Interval::<i32>::open_unbounded(0)],
+        };
+        assert_eq!(set.complement().intervals, vec![Interval::<i32>::closed(0, 0).unwrap()]);
+
+        let set = IntervalSet::<i32> { intervals: vec![Interval::universe()] };
+        assert!(set.complement().intervals.is_empty());
+
+        let set = IntervalSet::<i32> { intervals: vec![] };
+        assert_eq!(set.complement().intervals, vec![Interval::universe()]);
+        assert!(set.complement().complement().intervals.is_empty());
+
+        // Round trip back to the normalized set
+        let set = build_set(vec![
+            Interval::closed(4, 6).unwrap(),
+            Interval::open(-3, 0).unwrap(),
+            Interval::closed_open(0, 2).unwrap(),
+            Interval::open_unbounded(8)
+        ]);
+        assert_eq!(set.complement().complement().intervals, set.intervals);
+        assert_eq!(
+            set.complement().intervals,
+            vec![
+                Interval::unbounded_closed(-3),
+                Interval::closed_open(2, 4).unwrap(),
+                Interval::open_closed(6, 8).unwrap()
+            ]
+        );
+    }
+
+    /// A linear congruential generator for reproducible random tests.
+    struct Lcg(TestRng);
+
+    impl Lcg {
+        fn new(seed: u64) -> Self {
+            Self(TestRng::new(seed))
+        }
+
+        /// Generates a random integer in [low, high).
+        fn range(&mut self, low: i32, high: i32) -> i32 {
+            low + self.0.below((high - low) as u64) as i32
+        }
+
+        fn endpoint(&mut self, value: i32) -> Endpoint<i32> {
+            match self.range(0, 10) {
+                0 => Endpoint::Unbounded,
+                1..=4 => Endpoint::Open(value),
+                _ => Endpoint::Closed(value),
+            }
+        }
+
+        /// Generates a random set of integer intervals.
+        fn set(&mut self) -> IntervalSet<i32> {
+            let count = self.range(0, 6);
+            let intervals = (0..count)
+                .filter_map(|_| {
+                    let a = self.range(-20, 20);
+                    let b = self.range(-20, 20);
+                    let left = self.endpoint(a.min(b));
+                    let right = self.endpoint(a.max(b));
+                    Interval::new(left, right).ok()
+                })
+                .collect();
+
+            build_set(intervals)
+        }
+    }
+
+    #[test]
+    fn test_complement_round_trip() {
+        let mut rng = Lcg::new(42);
+        let universe = IntervalSet::<i32>::from(Interval::universe());
+
+        for _ in 0..5000 {
+            let set = rng.set();
+            let complement = set.complement();
+            assert!(complement.is_normalized());
+
+            assert_eq!((!!set.clone()).intervals, set.intervals);
+            assert_eq!(set.union(&complement).intervals, universe.intervals);
+            assert!(set.intersection(&complement).intervals.is_empty());
+
+            // De Morgan's laws
+            let other = rng.set();
+            assert_eq!(
+                set.union(&other).complement().intervals,
+                set.complement().intersection(&other.complement()).intervals
+            );
+            assert_eq!(
+                set.intersection(&other).complement().intervals,
+                set.complement().union(&other.complement()).intervals
+            );
+        }
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = IntervalSet::closed(0, 2).unwrap();
+        let b = IntervalSet::closed(1, 3).unwrap();
+        assert_eq!(
+            a.symmetric_difference(&b).intervals,
+            vec![Interval::closed_open(0, 1).unwrap(), Interval::open_closed(2, 3).unwrap()]
+        );
+        assert_eq!(a.clone() ^ b.clone(), a.symmetric_difference(&b));
+        assert_eq!(&a ^ &b, a.symmetric_difference(&b));
+
+        // The shared closed endpoint is in both sets
+        let b = IntervalSet::closed(2, 3).unwrap();
+        assert_eq!(
+            (a ^ &b).intervals,
+            vec![Interval::closed_open(0, 2).unwrap(), Interval::open_closed(2, 3).unwrap()]
+        );
+
+        let mut rng = Lcg::new(11);
+        for _ in 0..2000 {
+            let a = rng.set();
+            let b = rng.set();
+            let reference = a.difference(&b).union(&b.difference(&a));
+            let result = a.symmetric_difference(&b);
+            assert!(result.is_normalized());
+            assert_eq!(result.intervals, reference.intervals);
+        }
+    }
+
+    #[test]
+    fn test_interval_at() {
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::unbounded_open(0),
+                Interval::<i32>::closed_open(2, 5).unwrap(),
+                Interval::<i32>::open_closed(5, 8).unwrap()
+            ],
+        };
+
+        // Inside an interval
+        assert_eq!(set.interval_at(&-10), Some(&Interval::unbounded_open(0)));
+        assert_eq!(set.interval_at(&3), Some(&Interval::closed_open(2, 5).unwrap()));
+        assert_eq!(set.interval_at(&7), Some(&Interval::open_closed(5, 8).unwrap()));
+
+        // In a gap
+        assert_eq!(set.interval_at(&1), None);
+        assert_eq!(set.interval_at(&9), None);
+
+        // At a boundary
+        assert_eq!(set.interval_at(&0), None);
+        assert_eq!(set.interval_at(&2), Some(&Interval::closed_open(2, 5).unwrap()));
+        assert_eq!(set.interval_at(&5), None);
+        assert_eq!(set.interval_at(&8), Some(&Interval::open_closed(5, 8).unwrap()));
+    }
+
+    #[test]
+    fn test_locate() {
+        let set = build_set(vec![
+            Interval::closed_open(2, 5).unwrap(),
+            Interval::open_closed(5, 8).unwrap(),
+            Interval::closed(10, 12).unwrap()
+        ]);
+
+        // Before, inside, between and after the intervals
+        assert_eq!(set.locate(&0), Err(0));
+        assert_eq!(set.locate(&2), Ok(0));
+        assert_eq!(set.locate(&4), Ok(0));
+        assert_eq!(set.locate(&5), Err(1));
+        assert_eq!(set.locate(&8), Ok(1));
+        assert_eq!(set.locate(&9), Err(2));
+        assert_eq!(set.locate(&11), Ok(2));
+        assert_eq!(set.locate(&12), Ok(2));
+        assert_eq!(set.locate(&13), Err(3));
+
+        assert_eq!(IntervalSet::<i32>::empty().locate(&0), Err(0));
+        assert_eq!(build_set(vec![Interval::universe()]).locate(&0), Ok(0));
+    }
+
+    #[test]
+    fn count_locate_comparisons() {
+        use crate::sorting::Counted;
+
+        // The intervals [0, 1], [3, 4], [6, 7], ...
+        let values: Vec<i32> = (0..30_000).collect();
+        let counter = Cell::new(0);
+        let counted = |value: &i32| Counted { value: &values[*value as usize], counter: &counter };
+        let set = IntervalSet::from_intervals(
+            (0..10_000).map(|k| Interval::closed(counted(&(3 * k)), counted(&(3 * k + 1))).unwrap())
+        );
+
+        for value in [0, 1, 2, 15_000, 15_001, 29_998, 29_999] {
+            counter.set(0);
+            let located = set.locate(&counted(&value));
+            assert_eq!(located.is_ok(), value % 3 != 2);
+
+            // A linear scan would take thousands of comparisons
+            assert!(counter.get() < 50, "{} comparisons to locate {}", counter.get(), value);
+        }
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        let set = IntervalSet {
+            intervals: vec![Interval::<i32>::closed(0, 1).unwrap(), Interval::<i32>::open(1, 2).unwrap()],
+        };
+        assert!(!set.is_normalized());
+
+        let set = IntervalSet {
+            intervals: vec![Interval::<i32>::closed(2, 3).unwrap(), Interval::<i32>::closed(0, 1).unwrap()],
+        };
+        assert!(!set.is_normalized());
+
+        let set = IntervalSet {
+            intervals: vec![Interval::<i32>::closed_open(0, 1).unwrap(), Interval::<i32>::open(1, 2).unwrap()],
+        };
+        assert!(set.is_normalized());
+    }
+
+    #[test]
+    fn test_normalized() {
+        let a = build_set(vec![Interval::closed(0, 4).unwrap(), Interval::closed(6, 8).unwrap()]);
+        let b = build_set(vec![Interval::closed(3, 7).unwrap(), Interval::closed(10, 12).unwrap()]);
+        let c = build_set(vec![Interval::closed(1, 11).unwrap()]);
+
+        // Each intermediate set comes out normalized, so it is borrowed rather than copied
+        let union = a.union(&b);
+        assert!(matches!(union.normalized(), Cow::Borrowed(_)));
+        let set = union.intersection(&c);
+        assert!(matches!(set.normalized(), Cow::Borrowed(_)));
+
+        let set = set.translate_all(1).map_monotone(|x| x * 2, true);
+        assert!(matches!(set.normalized(), Cow::Borrowed(_)));
+        assert_eq!(set.intervals, vec![Interval::closed(4, 18).unwrap(), Interval::closed(22, 24).unwrap()]);
+
+        // A set breaking the invariant is normalized into a copy
+        let set = IntervalSet {
+            intervals: vec![Interval::closed(6, 8).unwrap(), Interval::closed(0, 4).unwrap(), Interval::open(3, 5).unwrap()],
+        };
+        let normalized = set.normalized();
+        assert!(matches!(normalized, Cow::Owned(_)));
+        assert_eq!(normalized.intervals, vec![Interval::closed_open(0, 5).unwrap(), Interval::closed(6, 8).unwrap()]);
+        assert_eq!(set.intervals.len(), 3);
+        assert_eq!(set, *normalized);
+    }
+
+    #[test]
+    fn test_to_bool_grid() {
+        let set = build_set(vec![Interval::closed_open(0, 4).unwrap(), Interval::open_closed(6, 8).unwrap()]);
+        assert_eq!(
+            set.to_bool_grid(-2, 2, 7),
+            vec![false, true, true, false, false, true, false]
+        );
+        assert_eq!(
+            set.to_bool_grid(3, 1, 6),
+            vec![true, false, false, false, true, true]
+        );
+        assert!(set.to_bool_grid(0, 1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_contains_range() {
+        let set = build_set(vec![Interval::closed_open(0, 10).unwrap(), Interval::closed(20, 30).unwrap()]);
+        assert!(set.contains_range(2..5));
+        assert!(set.contains_range(2..=5));
+        assert!(set.contains_range(0..10));
+        assert!(!set.contains_range(0..=10));
+        assert!(!set.contains_range(5..25));
+        assert!(set.contains_range(20..=30));
+        assert!(!set.contains_range(..5));
+        assert!(!set.contains_range(25..));
+        assert!(!set.contains_range(..));
+        assert!(set.contains_range(12..12));
+
+        let set = build_set(vec![Interval::unbounded_open(5), Interval::closed_unbounded(2)]);
+        assert!(set.contains_range(..));
+        assert!(set.contains_range(..5));
+        assert!(set.contains_range(2..));
+        assert!(set.contains_range(2..=5));
+    }
+
+    #[test]
+    fn test_merge_into_one() {
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(1, 2).unwrap()]);
+        assert_eq!(set.merge_into_one().unwrap(), Interval::closed(0, 2).unwrap());
+
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(3, 4).unwrap()]);
+        let error = set.merge_into_one().unwrap_err();
+        assert_eq!(error, IntervalSetError::NotConnected { components: 2 });
+        assert_eq!(
+            error.to_string(),
+            "interval set has 2 connected components instead of one"
+        );
+
+        let set = build_set(vec![]);
+        assert!(set.merge_into_one().is_err());
+    }
+
+    #[test]
+    fn test_fragmentation() {
+        let set = build_set(vec![
+            Interval::closed(0, 1).unwrap(),
+            Interval::open(4, 5).unwrap(),
+            Interval::closed_unbounded(6)
+        ]);
+        assert_eq!(set.fragmentation(), 2);
+        assert_eq!(set.largest_gap(), Some(Interval::open_closed(1, 4).unwrap()));
+
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(3, 4).unwrap(), Interval::closed(6, 7).unwrap()]);
+        assert_eq!(set.largest_gap(), Some(Interval::open(1, 3).unwrap()));
+
+        let set = build_set(vec![Interval::closed(0, 1).unwrap()]);
+        assert_eq!(set.fragmentation(), 0);
+        assert_eq!(set.largest_gap(), None);
+
+        let set = build_set(vec![]);
+        assert_eq!(set.fragmentation(), 0);
+        assert_eq!(set.largest_gap(), None);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set = build_set(vec![Interval::closed_open(0, 2).unwrap(), Interval::open_closed(3, 5).unwrap()]);
+        assert_eq!(set.gaps(), vec![Interval::closed(2, 3).unwrap()]);
+
+        let set = build_set(vec![
+            Interval::unbounded_open(0),
+            Interval::closed_open(1, 2).unwrap(),
+            Interval::open(2, 4).unwrap(),
+            Interval::closed_unbounded(6)
+        ]);
+        assert_eq!(
+            set.gaps(),
+            vec![Interval::closed_open(0, 1).unwrap(), Interval::closed(2, 2).unwrap(), Interval::closed_open(4, 6).unwrap()]
+        );
+
+        // The gaps fill the holes between the first and the last intervals
+        let filled = set.union(&build_set(set.gaps()));
+        assert_eq!(filled, IntervalSet::from(Interval::universe()));
+
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::open(4, 5).unwrap()]);
+        let filled = set.union(&build_set(set.gaps()));
+        assert_eq!(filled, IntervalSet::closed_open(0, 5).unwrap());
+
+        // Touching intervals are merged, leaving no gap
+        let set = build_set(vec![Interval::closed_open(0, 1).unwrap(), Interval::closed(1, 2).unwrap()]);
+        assert!(set.gaps().is_empty());
+
+        assert!(build_set(vec![Interval::universe()]).gaps().is_empty());
+        assert!(build_set(vec![Interval::closed(0, 1).unwrap()]).gaps().is_empty());
+        assert!(IntervalSet::<i32>::empty().gaps().is_empty());
+    }
+
+    #[test]
+    fn test_map() {
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(2, 3).unwrap()]);
+        assert_eq!(
+            set.map(|x| x * 1000).intervals,
+            vec![Interval::closed(0, 1000).unwrap(), Interval::closed(2000, 3000).unwrap()]
+        );
+
+        // Into another type, keeping the kinds of the endpoints
+        let set = build_set(vec![Interval::unbounded_open(-1), Interval::open_closed(0, 2).unwrap()]);
+        assert_eq!(
+            set.map(|&x| f64::from(x) + 0.5).intervals,
+            vec![Interval::unbounded_open(-0.5), Interval::open_closed(0.5, 2.5).unwrap()]
+        );
+
+        // Intervals brought together are merged
+        let set = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::closed(15, 30).unwrap()]);
+        assert_eq!(set.map(|x| x / 20), IntervalSet::closed(0, 1).unwrap());
+
+        // Intervals turned inside out are dropped
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed_unbounded(5)]);
+        assert_eq!(set.map(|x| -x).intervals, vec![Interval::closed_unbounded(-5)]);
+
+        assert!(IntervalSet::<i32>::empty().map(|x| x + 1).is_empty());
+    }
+
+    #[test]
+    fn test_map_monotone() {
+        let set = build_set(vec![Interval::closed(1, 2).unwrap(), Interval::closed(5, 6).unwrap()]);
+
+        let negated = set.map_monotone(|x| -x, false);
+        assert_eq!(
+            negated.intervals,
+            vec![Interval::closed(-6, -5).unwrap(), Interval::closed(-2, -1).unwrap()]
+        );
+
+        let set = build_set(vec![Interval::open_closed(1, 2).unwrap(), Interval::closed_unbounded(5)]);
+        assert_eq!(
+            set.map_monotone(|x| 3 * x, true).intervals,
+            vec![Interval::open_closed(3, 6).unwrap(), Interval::closed_unbounded(15)]
+        );
+        assert_eq!(
+            set.map_monotone(|x| 10 - x, false).intervals,
+            vec![Interval::unbounded_closed(5), Interval::closed_open(8, 9).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_restrict_above_and_below() {
+        let set = build_set(vec![
+            Interval::closed(0, 2).unwrap(),
+            Interval::closed(4, 6).unwrap(),
+            Interval::open(8, 10).unwrap()
+        ]);
+
+        assert_eq!(
+            set.restrict_above(5, true).intervals,
+            vec![Interval::closed(5, 6).unwrap(), Interval::open(8, 10).unwrap()]
+        );
+        assert_eq!(
+            set.restrict_above(6, true).intervals,
+            vec![Interval::closed(6, 6).unwrap(), Interval::open(8, 10).unwrap()]
+        );
+        assert_eq!(set.restrict_above(6, false).intervals, vec![Interval::open(8, 10).unwrap()]);
+        assert_eq!(set.restrict_above(-1, false).intervals, set.intervals);
+        assert!(set.restrict_above(10, true).intervals.is_empty());
+
+        assert_eq!(
+            set.restrict_below(5, false).intervals,
+            vec![Interval::closed(0, 2).unwrap(), Interval::closed_open(4, 5).unwrap()]
+        );
+        assert_eq!(
+            set.restrict_below(4, true).intervals,
+            vec![Interval::closed(0, 2).unwrap(), Interval::closed(4, 4).unwrap()]
+        );
+        assert_eq!(set.restrict_below(4, false).intervals, vec![Interval::closed(0, 2).unwrap()]);
+        assert_eq!(set.restrict_below(12, true).intervals, set.intervals);
+        assert!(set.restrict_below(0, false).intervals.is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let set = build_set(vec![
+            Interval::closed(0, 1).unwrap(),
+            Interval::open(1, 3).unwrap(),
+            Interval::closed(5, 6).unwrap()
+        ]);
+        assert!(set.validate().is_ok());
+        assert!(build_set(vec![]).validate().is_ok());
+
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::closed(0, 1).unwrap(),
+                Interval::closed(5, 6).unwrap(),
+                Interval::open(2, 3).unwrap()
+            ],
+        };
+        assert_eq!(set.validate(), Err(IntervalSetError::UnnormalizedIntervalAt { index: 1 }));
+
+        let set = IntervalSet {
+            intervals: vec![Interval::closed(0, 2).unwrap(), Interval::closed(1, 3).unwrap()],
+        };
+        assert_eq!(set.validate(), Err(IntervalSetError::UnnormalizedIntervalAt { index: 0 }));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<IntervalSet<i32>>();
+        assert_send_sync::<Interval<i32>>();
+        assert_send_sync::<Endpoint<i32>>();
+
+        // Share a set across threads
+        let set = std::sync::Arc::new(IntervalSet::closed(0, 10).unwrap());
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let set = std::sync::Arc::clone(&set);
+                std::thread::spawn(move || set.interval_at(&(i * 4)).is_some())
+            })
+            .collect();
+        let found: Vec<bool> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+        assert_eq!(found, vec![true, true, true, false]);
+    }
+
+    #[test]
+    fn union_scales_linearly() {
+        use crate::sorting::Counted;
+
+        let n = 100_000;
+        let values: Vec<i32> = (0..4 * n).collect();
+        let counter = Cell::new(0);
+        let counted = |value: i32| Counted { value: &values[value as usize], counter: &counter };
+
+        // Interleaved sets whose union keeps every interval apart,
+        // and overlapping sets whose union merges every pair
+        let set = IntervalSet::from_intervals(
+            (0..n).map(|i| Interval::closed(counted(4 * i), counted(4 * i + 1)).unwrap())
+        );
+        let interleaved = IntervalSet::from_intervals(
+            (0..n).map(|i| Interval::closed(counted(4 * i + 2), counted(4 * i + 3)).unwrap())
+        );
+        let overlapping = IntervalSet::from_intervals(
+            (0..n).map(|i| Interval::open(counted(4 * i + 1), counted(4 * i + 2)).unwrap())
+        );
+
+        // A quadratic merge would take billions of comparisons
+        counter.set(0);
+        let union = set.union(&interleaved);
+        assert_eq!(union.intervals.len(), 2 * (n as usize));
+        assert!(counter.get() < 20 * (n as usize), "{} comparisons", counter.get());
+
+        counter.set(0);
+        let merged = set.union(&overlapping);
+        assert_eq!(merged.intervals.len(), n as usize);
+        assert!(counter.get() < 20 * (n as usize), "{} comparisons", counter.get());
+    }
+
+    #[test]
+    fn test_nearest_interval() {
+        let set = build_set(vec![
+            Interval::closed(9, 10).unwrap(),
+            Interval::closed_open(14, 16).unwrap(),
+            Interval::closed_unbounded(20)
+        ]);
+
+        assert_eq!(set.nearest_interval(&11), Some(&Interval::closed(9, 10).unwrap()));
+        assert_eq!(set.nearest_interval(&13), Some(&Interval::closed_open(14, 16).unwrap()));
+        assert_eq!(set.nearest_interval(&15), Some(&Interval::closed_open(14, 16).unwrap()));
+        assert_eq!(set.nearest_interval(&0), Some(&Interval::closed(9, 10).unwrap()));
+        assert_eq!(set.nearest_interval(&100), Some(&Interval::closed_unbounded(20)));
+
+        // The left interval wins a tie
+        assert_eq!(set.nearest_interval(&12), Some(&Interval::closed(9, 10).unwrap()));
+
+        assert_eq!(build_set(vec![]).nearest_interval(&0), None);
+    }
+
+    #[test]
+    fn test_contributions() {
+        let set = build_set(vec![
+            Interval::closed(0, 4).unwrap(),
+            Interval::closed(10, 20).unwrap(),
+            Interval::closed(30, 40).unwrap(),
+            Interval::closed_unbounded(50)
+        ]);
+        let other = build_set(vec![
+            Interval::closed(-1, 5).unwrap(),
+            Interval::closed(12, 14).unwrap(),
+            Interval::open(18, 25).unwrap(),
+            Interval::closed_unbounded(60)
+        ]);
+
+        assert_eq!(
+            set.contributions(&other),
+            vec![
+                // Fully overlapped
+                (Interval::closed(0, 4).unwrap(), Some(4)),
+                // Partially overlapped in two places
+                (Interval::closed(10, 20).unwrap(), Some(4)),
+                (Interval::closed(30, 40).unwrap(), Some(0)),
+                (Interval::closed_unbounded(50), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snap_to_values() {
+        let grid = [0.0, 2.0, 4.0, 6.0, 8.0];
+
+        let set = IntervalSet::open(2.3, 7.8).unwrap();
+        assert_eq!(set.snap_to_values(&grid).intervals, vec![Interval::closed(4.0, 6.0).unwrap()]);
+
+        // Open endpoints on grid values exclude them
+        let set = IntervalSet::open(2.0, 6.0).unwrap();
+        assert_eq!(set.snap_to_values(&grid).intervals, vec![Interval::closed(4.0, 4.0).unwrap()]);
+
+        // Intervals without grid values are dropped
+        let set = IntervalSet::closed(0.5, 1.5)
+            .unwrap()
+            .union(&IntervalSet::closed_open(1.8, 4.5).unwrap())
+            .union(&IntervalSet::open_unbounded(4.5));
+        assert_eq!(
+            set.snap_to_values(&grid).intervals,
+            vec![Interval::closed(2.0, 4.0).unwrap(), Interval::closed_unbounded(6.0)]
+        );
+    }
+
+    #[test]
+    fn test_insert_point() {
+        let mut set = build_set(vec![Interval::closed_open(0, 1).unwrap(), Interval::open(1, 3).unwrap()]);
+
+        set.insert_point(5);
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::closed_open(0, 1).unwrap(),
+                Interval::open(1, 3).unwrap(),
+                Interval::closed(5, 5).unwrap()
+            ]
+        );
+
+        // The point fills the hole between the two intervals
+        set.insert_point(1);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::closed_open(0, 3).unwrap(), Interval::closed(5, 5).unwrap()]
+        );
+
+        set.insert_point(3);
+        set.insert_point(2);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::closed(0, 3).unwrap(), Interval::closed(5, 5).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_remove_point() {
+        let mut set = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::closed(12, 12).unwrap()]);
+
+        set.remove_point(&4);
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::closed_open(0, 4).unwrap(),
+                Interval::open_closed(4, 10).unwrap(),
+                Interval::closed(12, 12).unwrap()
+            ]
+        );
+
+        set.remove_point(&0);
+        set.remove_point(&10);
+        set.remove_point(&12);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::open(0, 4).unwrap(), Interval::open(4, 10).unwrap()]
+        );
+
+        // Removing non-members does nothing
+        let expected = set.intervals.clone();
+        set.remove_point(&4);
+        set.remove_point(&-1);
+        set.remove_point(&11);
+        assert_eq!(set.intervals, expected);
+    }
+
+    #[test]
+    fn test_count_overlapping() {
+        let set = build_set(vec![Interval::closed_open(9, 12).unwrap(), Interval::closed(14, 17).unwrap()]);
+        let queries = vec![
+            Interval::closed(8, 9).unwrap(),
+            Interval::closed(12, 13).unwrap(),
+            Interval::open(12, 14).unwrap(),
+            Interval::closed(10, 15).unwrap(),
+            Interval::open(17, 20).unwrap(),
+            Interval::closed_unbounded(16),
+            Interval::unbounded_open(9)
+        ];
+
+        // [8, 9], [10, 15] and [16, +∞) conflict
+        assert_eq!(set.count_overlapping(&queries), 3);
+        assert_eq!(set.count_overlapping(&[]), 0);
+    }
+
+    #[test]
+    fn test_find_first_conflict() {
+        let set = build_set(vec![Interval::closed_open(9, 12).unwrap()]);
+        let requests = vec![
+            Interval::closed_open(13, 14).unwrap(),
+            Interval::closed_open(12, 13).unwrap(),
+            Interval::closed_open(13, 15).unwrap(),
+            Interval::closed(10, 11).unwrap()
+        ];
+
+        // The third request conflicts with the first one
+        assert_eq!(
+            set.find_first_conflict(&requests),
+            Some((2, Interval::closed_open(13, 15).unwrap()))
+        );
+
+        // The last request conflicts with the set
+        let requests = vec![requests[0].clone(), requests[3].clone()];
+        assert_eq!(set.find_first_conflict(&requests), Some((1, Interval::closed(10, 11).unwrap())));
+        assert_eq!(set.find_first_conflict(&requests[..1]), None);
+
+        // Requests arrive in reverse order, and the last accepted one joins two earlier ones
+        let mut requests: Vec<_> = (0..1000)
+            .rev()
+            .map(|k| Interval::closed_open(20 + 2 * k, 21 + 2 * k).unwrap())
+            .collect();
+        requests.push(Interval::closed_open(1001, 1002).unwrap());
+        assert_eq!(set.find_first_conflict(&requests), None);
+
+        requests.push(Interval::closed(1002, 1002).unwrap());
+        assert_eq!(set.find_first_conflict(&requests), Some((1001, Interval::closed(1002, 1002).unwrap())));
+    }
+
+    #[test]
+    fn test_merge_sorted_slice() {
+        let mut set = build_set(vec![Interval::closed(0, 2).unwrap(), Interval::closed(5, 7).unwrap()]);
+
+        set.merge_sorted_slice(&[Interval::closed(6, 9).unwrap(), Interval::closed(12, 13).unwrap()]);
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::closed(0, 2).unwrap(),
+                Interval::closed(5, 9).unwrap(),
+                Interval::closed(12, 13).unwrap()
+            ]
+        );
+
+        set.merge_sorted_slice(&[Interval::open(-3, 0).unwrap(), Interval::open(9, 12).unwrap()]);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::open_closed(-3, 2).unwrap(), Interval::closed(5, 13).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_complement_in() {
+        let universe = build_set(vec![
+            Interval::closed(9, 12).unwrap(),
+            Interval::closed(14, 18).unwrap(),
+            Interval::closed_unbounded(20)
+        ]);
+        let set = build_set(vec![Interval::closed_open(10, 15).unwrap(), Interval::closed(17, 21).unwrap()]);
+
+        assert_eq!(
+            set.complement_in(&universe).intervals,
+            vec![
+                Interval::closed_open(9, 10).unwrap(),
+                Interval::closed_open(15, 17).unwrap(),
+                Interval::open_unbounded(21)
+            ]
+        );
+        assert_eq!(set.complement_in(&IntervalSet::from(Interval::universe())).intervals, set.complement().intervals);
+    }
+
+    #[test]
+    fn test_subtract_all() {
+        let base = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::closed(20, 30).unwrap()]);
+        let subtrahends = vec![
+            build_set(vec![Interval::closed(2, 4).unwrap(), Interval::open(25, 40).unwrap()]),
+            build_set(vec![Interval::open(3, 6).unwrap()]),
+            build_set(vec![Interval::unbounded_open(1), Interval::closed(8, 21).unwrap()])
+        ];
+
+        let set = IntervalSet::subtract_all(base.clone(), &subtrahends);
+        assert_eq!(
+            set.intervals,
+            vec![
+                Interval::closed_open(1, 2).unwrap(),
+                Interval::closed_open(6, 8).unwrap(),
+                Interval::open_closed(21, 25).unwrap()
+            ]
+        );
+
+        let folded = subtrahends.iter().fold(base.clone(), |set, subtrahend| set.difference(subtrahend));
+        assert_eq!(set.intervals, folded.intervals);
+
+        assert_eq!(IntervalSet::subtract_all(base.clone(), &[]).intervals, base.intervals);
+    }
+
+    #[test]
+    fn test_first_fit() {
+        let busy = build_set(vec![
+            Interval::closed(9, 10).unwrap(),
+            Interval::closed_open(11, 14).unwrap(),
+            Interval::closed(15, 16).unwrap()
+        ]);
+        let afternoon = Interval::closed(9, 18).unwrap();
+
+        assert_eq!(busy.first_fit(1, &afternoon), Some(Interval::open(10, 11).unwrap()));
+
+        // The gaps (10, 11) and [14, 15) are too small
+        assert_eq!(busy.first_fit(2, &afternoon), Some(Interval::open_closed(16, 18).unwrap()));
+        assert_eq!(busy.first_fit(3, &afternoon), None);
+
+        let evening = Interval::closed_unbounded(15);
+        assert_eq!(busy.first_fit(3, &evening), Some(Interval::open_closed(16, 19).unwrap()));
+    }
+
+    #[test]
+    fn test_largest_free_slot() {
+        let busy = build_set(vec![
+            Interval::closed(9, 10).unwrap(),
+            Interval::closed_open(12, 14).unwrap(),
+            Interval::closed(17, 18).unwrap()
+        ]);
+
+        // The free slots are [8, 9), (10, 12), [14, 17) and (18, 19]
+        let week = Interval::closed(8, 19).unwrap();
+        assert_eq!(busy.largest_free_slot(&week), Some(Interval::closed_open(14, 17).unwrap()));
+
+        // The earliest of (10, 12) and [14, 16] wins the tie
+        let window = Interval::closed(10, 16).unwrap();
+        assert_eq!(busy.largest_free_slot(&window), Some(Interval::open(10, 12).unwrap()));
+
+        let window = Interval::closed(17, 20).unwrap();
+        assert_eq!(busy.largest_free_slot(&window), Some(Interval::open_closed(18, 20).unwrap()));
+
+        assert_eq!(busy.largest_free_slot(&Interval::at_least(10)), Some(Interval::open_unbounded(18)));
+        assert_eq!(busy.largest_free_slot(&Interval::closed(12, 13).unwrap()), None);
+    }
+
+    #[test]
+    fn test_format_precision() {
+        let set = IntervalSet::closed(0.0, 1.0)
+            .unwrap()
+            .union(&IntervalSet::open(2.5, 3.24567).unwrap());
+        assert_eq!(set.format_precision(2), "[0.00, 1.00] ∪ (2.50, 3.25)");
+
+        let set: IntervalSet<f64> = IntervalSet::closed(0.0, 1.0)
+            .unwrap()
+            .intersection(&IntervalSet::closed(2.0, 3.0).unwrap());
+        assert_eq!(set.format_precision(2), "∅");
+    }
+
+    #[test]
+    fn test_component_widths() {
+        let set = build_set(vec![
+            Interval::closed(0, 1).unwrap(),
+            Interval::open(4, 8).unwrap(),
+            Interval::closed_unbounded(10)
+        ]);
+        assert_eq!(set.component_widths().collect::<Vec<_>>(), vec![Some(1), Some(4), None]);
+
+        let widths: Vec<i32> = set.component_widths().flatten().collect();
+        assert_eq!(widths.iter().max(), Some(&4));
+    }
+
+    #[test]
+    fn test_eq_ignoring_points() {
+        let a = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::closed(5, 5).unwrap()]);
+        let b = build_set(vec![Interval::closed(0, 1).unwrap()]);
+        assert_eq!(a.without_points().intervals, b.intervals);
+        assert!(a.eq_ignoring_points(&b));
+        assert!(b.eq_ignoring_points(&a));
+
+        let a = build_set(vec![Interval::closed(3, 3).unwrap(), Interval::closed(7, 7).unwrap()]);
+        let b = build_set(vec![]);
+        assert!(a.eq_ignoring_points(&b));
+
+        // Points attached to an interval are not isolated
+        let a = build_set(vec![Interval::closed_open(0, 1).unwrap(), Interval::closed(1, 1).unwrap()]);
+        let b = build_set(vec![Interval::closed_open(0, 1).unwrap()]);
+        assert!(!a.eq_ignoring_points(&b));
+    }
+
+    #[test]
+    fn test_boundary_points() {
+        let set = build_set(vec![Interval::open_closed(2, 3).unwrap(), Interval::closed(0, 1).unwrap()]);
+        assert_eq!(
+            set.boundary_points(),
+            vec![
+                (0, Endpoint::Closed(0)),
+                (1, Endpoint::Closed(1)),
+                (2, Endpoint::Open(2)),
+                (3, Endpoint::Closed(3))
+            ]
+        );
+
+        let set = build_set(vec![Interval::unbounded_open(0), Interval::closed_unbounded(5)]);
+        assert_eq!(set.boundary_points(), vec![(0, Endpoint::Open(0)), (5, Endpoint::Closed(5))]);
+    }
+
+    #[test]
+    fn test_to_pairs() {
+        let set = build_set(vec![Interval::closed(0, 1).unwrap(), Interval::open_closed(2, 3).unwrap()]);
+        assert_eq!(set.to_pairs(), vec![(Some(0), Some(1)), (Some(2), Some(3))]);
+        assert_eq!(set.to_closed_pairs(), Some(vec![(0, 1), (2, 3)]));
+
+        let set = build_set(vec![Interval::unbounded_open(0), Interval::closed(2, 3).unwrap()]);
+        assert_eq!(set.to_pairs(), vec![(None, Some(0)), (Some(2), Some(3))]);
+        assert_eq!(set.to_closed_pairs(), None);
+    }
+
+    #[test]
+    fn test_translate_all() {
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed(0, 1).unwrap(),
+                Interval::<i32>::open_unbounded(3)
+            ],
+        };
+        let set = set.translate_all(2);
+        assert_eq!(
+            set.intervals,
+            vec![Interval::<i32>::closed(2, 3).unwrap(), Interval::<i32>::open_unbounded(5)]
+        );
+
+        // Rounding makes the intervals overlap, so they are merged
+        let set = IntervalSet::from_intervals(vec![
+            Interval::<f64>::closed(0.0, 0.5).unwrap(),
+            Interval::<f64>::closed(1.0, 2.0).unwrap()
+        ]);
+        let offset = (1u64 << 53) as f64;
+        assert_eq!(
+            set.translate_all(offset).intervals,
+            vec![Interval::<f64>::closed(offset, offset + 2.0).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_reflect() {
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::unbounded_open(0),
+                Interval::<i32>::closed(1, 2).unwrap(),
+                Interval::<i32>::closed_open(5, 6).unwrap()
+            ],
+        };
+
+        // The order of the intervals is reversed
+        let reflected = set.reflect(0);
+        assert_eq!(
+            reflected.intervals,
+            vec![
+                Interval::<i32>::open_closed(-6, -5).unwrap(),
+                Interval::<i32>::closed(-2, -1).unwrap(),
+                Interval::<i32>::open_unbounded(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_buffer() {
+        // The gap closes
+        let set = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::closed(12, 20).unwrap()]);
+        assert_eq!(set.buffer(1).intervals, vec![Interval::closed(-1, 21).unwrap()]);
+
+        // The gap remains
+        let set = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::open(13, 20).unwrap()]);
+        assert_eq!(
+            set.buffer(1).intervals,
+            vec![Interval::closed(-1, 11).unwrap(), Interval::open(12, 21).unwrap()]
+        );
+
+        // A negative margin erodes the set
+        let set = build_set(vec![Interval::closed(0, 10).unwrap(), Interval::closed(12, 14).unwrap()]);
+        assert_eq!(set.buffer(-2).intervals, vec![Interval::closed(2, 8).unwrap()]);
+        assert!(set.buffer(-6).is_empty());
     }
 }
@@ -0,0 +1,869 @@
+use std::cmp::Ordering;
+use num_traits::{ FromPrimitive, Num, PrimInt, ToPrimitive };
+use super::{ Endpoint, Interval, IntervalSet, IntervalSetError, IntervalSetResult, coverage_over_time };
+
+/// Numeric types that intervals can do arithmetic with,
+/// e.g., `f32`, `f64`, `i32`, `i64` and `BigInt`.
+pub trait Numeric: Num + PartialOrd + Clone {}
+
+impl<T: Num + PartialOrd + Clone> Numeric for T {}
+
+impl<T: Numeric> Interval<T> {
+    /// Gets the midpoint of the interval.
+    /// For integers, the midpoint is rounded towards the low value.
+    /// If the interval is unbounded, returns `None`.
+    pub fn midpoint(&self) -> Option<T> {
+        Some(halfway(self.low()?, self.high()?))
+    }
+
+    /// Scales the interval by multiplying both endpoints by the factor.
+    /// If the factor is negative, the endpoints swap sides.
+    /// If the factor is zero, the interval collapses to the degenerate interval `[0]`.
+    ///
+    /// # Panics
+    /// Panics if an endpoint value becomes not comparable with itself,
+    /// e.g., scaling by `f64::NAN`.
+    pub fn scale(&self, factor: T) -> Self {
+        if factor == T::zero() {
+            return Self::closed(T::zero(), T::zero()).unwrap();
+        }
+
+        let reverse = factor < T::zero();
+        self.map_endpoints(|value| value * factor.clone(), reverse)
+    }
+
+    /// Iterates over the integer points of the interval in ascending order,
+    /// e.g., `(0, 5]` yields `1, 2, 3, 4, 5`.
+    /// If the interval is unbounded, yields nothing.
+    pub fn iter_points(&self) -> impl Iterator<Item = T> where T: PrimInt {
+        let bounds = integer_bounds(self).flatten();
+
+        std::iter::successors(bounds.map(|(first, _)| first), move |&point| {
+            let (_, last) = bounds?;
+            if point < last { Some(point + T::one()) } else { None }
+        })
+    }
+
+    /// Iterates over the integer points of the interval in a modular domain in ascending order,
+    /// e.g., `[22, 26)` with modulus 24 yields `22, 23, 0, 1`.
+    /// The interval wraps around when its high value reaches the modulus,
+    /// and every point is reduced to the range `[0, modulus)`.
+    /// If the interval is unbounded, yields nothing.
+    ///
+    /// # Panics
+    /// Panics if the modulus is not positive.
+    pub fn iter_points_modular(&self, modulus: T) -> impl Iterator<Item = T> where T: PrimInt {
+        assert!(modulus > T::zero(), "modulus must be positive");
+
+        self.iter_points().map(move |point| {
+            let remainder = point % modulus;
+            if remainder < T::zero() { remainder + modulus } else { remainder }
+        })
+    }
+
+    /// Gets `n` evenly spaced values from the low value to the high value inclusively.
+    /// The values of open endpoints are included as well.
+    /// If `n` is one, only the low value is returned.
+    /// For integers, each value is rounded towards the low value,
+    /// and no intermediate value exceeds the width, so nothing overflows.
+    /// If the interval is unbounded, or `n - 1` does not fit in `T`,
+    /// e.g., 300 samples of `u8`, returns `None`.
+    pub fn sample_points(&self, n: usize) -> Option<Vec<T>> where T: FromPrimitive {
+        let low = self.low()?;
+        let width = self.high()? - low.clone();
+
+        if n <= 1 {
+            return Some(vec![low; n]);
+        }
+
+        // The number of steps between the first and last samples
+        let steps = T::from_usize(n - 1)?;
+
+        // The i-th value is offset by width * i / steps from the low value,
+        // where width = step * steps + remainder
+        let remainder = width.clone() % steps.clone();
+        let step = (width - remainder.clone()) / steps.clone();
+
+        // Accumulate the offset as whole + carry / steps,
+        // where carry stays below steps and is carried into whole instead of growing
+        let mut points = Vec::with_capacity(n);
+        let mut whole = T::zero();
+        let mut carry = T::zero();
+        for i in 0..n {
+            if i > 0 {
+                whole = whole + step.clone();
+
+                // Add the remainder to the carry without exceeding steps
+                let room = steps.clone() - remainder.clone();
+                if carry >= room {
+                    carry = carry - room;
+                    whole = whole + T::one();
+                } else {
+                    carry = carry + remainder.clone();
+                }
+            }
+
+            points.push(low.clone() + whole.clone() + carry.clone() / steps.clone());
+        }
+
+        Some(points)
+    }
+}
+
+// The measures of sets are summed with `total_length_with_overlap`,
+// which starts from `T::zero()` rather than the default value,
+// and nothing is counted twice since the intervals of a set are separated
+impl<T: Numeric> IntervalSet<T> {
+    /// Scales all intervals in the set by the factor.
+    /// Intervals that come to touch or overlap after scaling are merged.
+    /// See [`Interval::scale`] for how negative and zero factors are handled, and when it panics.
+    pub fn scale_all(&self, factor: T) -> Self {
+        let mut set = Self {
+            intervals: self.intervals
+                .iter()
+                .map(|interval| interval.scale(factor.clone()))
+                .collect(),
+        };
+        set.normalize();
+
+        set
+    }
+
+    /// Gets the earliest portion of the set whose total length is at most the budget,
+    /// cutting the interval where the budget runs out,
+    /// e.g., truncating `[0, 5] ∪ [10, 20]` to 8 gives `[0, 5] ∪ [10, 13]`.
+    /// An interval unbounded on the left has no earliest portion, so nothing is kept from it.
+    pub fn truncate_to_measure(&self, budget: T) -> Self {
+        let mut intervals = Vec::new();
+        let mut remaining = budget;
+
+        for interval in &self.intervals {
+            if remaining <= T::zero() {
+                break;
+            }
+
+            let Some(low) = interval.low() else {
+                break;
+            };
+
+            match interval.width() {
+                Some(width) if width <= remaining => {
+                    remaining = remaining - width;
+                    intervals.push(interval.clone());
+                }
+                _ => {
+                    let end = Endpoint::Closed(low + remaining);
+                    intervals.extend(Interval::new(interval.left().clone(), end).ok());
+                    break;
+                }
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Splits the set into consecutive chunks each covering `chunk_size` in total length,
+    /// except that the last chunk may cover less.
+    /// An interval is cut where a chunk fills up,
+    /// and the cut point goes to the earlier chunk,
+    /// e.g., chunking `[0, 10]` by 3 gives `[0, 3]`, `(3, 6]`, `(6, 9]` and `(9, 10]`.
+    /// If the set is unbounded or `chunk_size` is not positive, returns no chunks.
+    /// Otherwise, the chunks always cover the whole set.
+    /// If adding `chunk_size` to an endpoint no longer changes it, e.g., adding 1 to 1e16 in `f64`,
+    /// the rest of the set becomes the last chunk, which may then cover more than `chunk_size`.
+    pub fn chunk_by_measure(&self, chunk_size: T) -> Vec<Self> {
+        let is_bounded = self.intervals.iter().all(Interval::is_bounded);
+        if !is_bounded || chunk_size <= T::zero() {
+            return Vec::new();
+        }
+
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.intervals.is_empty() {
+            let chunk = rest.truncate_to_measure(chunk_size.clone());
+            if chunk.intervals == rest.intervals {
+                chunks.push(chunk);
+                break;
+            }
+
+            // Keep the rest as the last chunk where the chunk size is lost to the precision of T
+            let Some(last) = chunk.intervals.last() else {
+                chunks.push(rest);
+                break;
+            };
+
+            // It is safe to unwrap since the chunk is bounded
+            let end = last.high().unwrap();
+            rest = rest.restrict_above(end, false);
+            chunks.push(chunk);
+        }
+
+        chunks
+    }
+
+    /// Gets the total length of the overlap of the two sets,
+    /// e.g., how many hours two calendars conflict.
+    /// If the overlap is unbounded, returns `None`.
+    pub fn overlap_measure(&self, other: &Self) -> Option<T> {
+        total_length_with_overlap(&self.intersection(other).intervals)
+    }
+
+    /// Gets the Jaccard similarity of the two sets,
+    /// i.e., the measure of their intersection divided by the measure of their union,
+    /// which ranges from 0.0 for disjoint sets to 1.0 for identical ones.
+    /// Two sets of zero measure, e.g., two empty sets, are regarded as identical.
+    /// If either set is unbounded, returns `None`.
+    pub fn jaccard(&self, other: &Self) -> Option<f64> where T: ToPrimitive {
+        let union = total_length_with_overlap(&self.union(other).intervals)?.to_f64()?;
+        let intersection = total_length_with_overlap(&self.intersection(other).intervals)?.to_f64()?;
+
+        if union == 0.0 {
+            return Some(1.0);
+        }
+
+        Some(intersection / union)
+    }
+
+    /// Checks if the set covers at least `n` integer points within the window.
+    /// The counting stops as soon as `n` points are found,
+    /// so the whole intersection is never computed.
+    pub fn covers_at_least(&self, window: &Interval<T>, n: usize) -> bool where T: PrimInt {
+        if n == 0 {
+            return true;
+        }
+
+        // Skip the intervals lying entirely before the window
+        let start = self.count_intervals_before(window);
+
+        let mut count: u128 = 0;
+        for interval in &self.intervals[start..] {
+            // The rest of the intervals lie after the window
+            let Some(overlap) = interval.intersection(window) else {
+                break;
+            };
+
+            let Some(points) = count_integer_points(&overlap) else {
+                // Infinitely many points
+                return true;
+            };
+
+            count = count.saturating_add(points);
+            if count >= (n as u128) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Gets the ratio of the symmetric difference to the union of the two sets,
+    /// which ranges from 0.0 for identical sets to 1.0 for disjoint ones,
+    /// e.g., how much a schedule changed since yesterday.
+    /// Two sets of zero measure, e.g., two empty sets, are regarded as identical.
+    /// If either set is unbounded, returns `None`.
+    pub fn change_ratio(&self, other: &Self) -> Option<f64> where T: ToPrimitive {
+        let union = total_length_with_overlap(&self.union(other).intervals)?;
+        let intersection = total_length_with_overlap(&self.intersection(other).intervals)?;
+
+        // The symmetric difference is the union without the intersection
+        let changed = (union.clone() - intersection).to_f64()?;
+        let union = union.to_f64()?;
+
+        if union == 0.0 {
+            return Some(0.0);
+        }
+
+        Some(changed / union)
+    }
+
+    /// Replaces each interval with the grid cells `[origin + k * step, origin + (k + 1) * step)`
+    /// containing any of its integer points, and merges them,
+    /// e.g., `[3, 12]` becomes `[0, 15)` on the grid of step 5 from origin 0.
+    /// Unbounded sides stay unbounded, and intervals without integer points are dropped.
+    /// Returns an error if a cell boundary does not fit in `T`.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive.
+    pub fn to_grid_cells(&self, step: T, origin: T) -> IntervalSetResult<Self> where T: PrimInt {
+        assert!(step > T::zero(), "step must be positive");
+
+        // Gets the start of the cell containing the point
+        let cell_start = |point: T| {
+            let offset = point.checked_sub(&origin).ok_or(IntervalSetError::GridCellOverflow)?;
+            let mut k = offset / step;
+            if offset % step < T::zero() {
+                k = k - T::one();
+            }
+
+            k.checked_mul(&step)
+                .and_then(|start| origin.checked_add(&start))
+                .ok_or(IntervalSetError::GridCellOverflow)
+        };
+
+        // Gets the end of the cell containing the point
+        let cell_end = |point: T| {
+            cell_start(point)?.checked_add(&step).ok_or(IntervalSetError::GridCellOverflow)
+        };
+
+        let mut intervals = Vec::with_capacity(self.intervals.len());
+        for interval in &self.intervals {
+            // Drop the intervals without integer points
+            if let Some(None) = integer_bounds(interval) {
+                continue;
+            }
+
+            // Get the cells of the least and the greatest integers in the interval
+            let left = match interval.left() {
+                Endpoint::Open(low) => {
+                    let Some(first) = low.checked_add(&T::one()) else {
+                        continue;
+                    };
+                    Endpoint::Closed(cell_start(first)?)
+                }
+                Endpoint::Closed(low) => Endpoint::Closed(cell_start(*low)?),
+                Endpoint::Unbounded => Endpoint::Unbounded,
+            };
+            let right = match interval.right() {
+                Endpoint::Open(high) => {
+                    let Some(last) = high.checked_sub(&T::one()) else {
+                        continue;
+                    };
+                    Endpoint::Open(cell_end(last)?)
+                }
+                Endpoint::Closed(high) => Endpoint::Open(cell_end(*high)?),
+                Endpoint::Unbounded => Endpoint::Unbounded,
+            };
+
+            intervals.extend(Interval::new(left, right).ok());
+        }
+
+        Ok(Self::from_intervals(intervals))
+    }
+
+    /// Renders the set as a row of `width` characters spanning the viewport `[start, end]`,
+    /// where covered columns are `#` and the others are `.`.
+    /// The columns are sampled at evenly spaced points with the first at `start` and the last at `end`,
+    /// and a column is covered if its sample point is in the set.
+    /// If `start` is greater than `end`, or `width - 1` does not fit in `T`, returns an empty string.
+    pub fn to_ascii_art(&self, start: T, end: T, width: usize) -> String where T: FromPrimitive {
+        let Some(points) = Interval::closed(start, end)
+            .ok()
+            .and_then(|viewport| viewport.sample_points(width)) else {
+            return String::new();
+        };
+
+        coverage_over_time(self, &points)
+            .into_iter()
+            .map(|covered| if covered { '#' } else { '.' })
+            .collect()
+    }
+}
+
+/// Gets the least and the greatest integers in the interval,
+/// or `Some(None)` if the interval contains no integers.
+/// If the interval is unbounded, returns `None`.
+fn integer_bounds<T: PrimInt>(interval: &Interval<T>) -> Option<Option<(T, T)>> {
+    let first = match interval.left() {
+        Endpoint::Open(low) => low.checked_add(&T::one()),
+        Endpoint::Closed(low) => Some(*low),
+        Endpoint::Unbounded => {
+            return None;
+        }
+    };
+    let last = match interval.right() {
+        Endpoint::Open(high) => high.checked_sub(&T::one()),
+        Endpoint::Closed(high) => Some(*high),
+        Endpoint::Unbounded => {
+            return None;
+        }
+    };
+
+    Some(first.zip(last).filter(|(first, last)| first <= last))
+}
+
+/// Counts the integer points in the interval.
+/// If the interval is unbounded, returns `None`.
+fn count_integer_points<T: PrimInt>(interval: &Interval<T>) -> Option<u128> {
+    let Some((first, last)) = integer_bounds(interval)? else {
+        return Some(0);
+    };
+
+    // The difference may overflow a signed type, but never an i128
+    let difference = match last.checked_sub(&first).and_then(|difference| difference.to_u128()) {
+        Some(difference) => difference,
+        None =>
+            last
+                .to_i128()
+                .zip(first.to_i128())
+                .and_then(|(last, first)| last.checked_sub(first))
+                .map_or(u128::MAX, |difference| difference as u128),
+    };
+
+    Some(difference.saturating_add(1))
+}
+
+/// Searches the interval for the point where `target` returns `Ordering::Equal`
+/// by repeatedly halving it.
+/// `target` should return `Ordering::Less` for points below the wanted point
+/// and `Ordering::Greater` for points above it.
+/// Returns the midpoint of the remaining part after the iterations,
+/// or `None` if the interval is unbounded.
+pub fn bisect<T: Numeric, F: Fn(&T) -> Ordering>(
+    interval: &Interval<T>,
+    target: F,
+    iterations: usize
+) -> Option<T> {
+    let mut low = interval.low()?;
+    let mut high = interval.high()?;
+
+    for _ in 0..iterations {
+        let mid = halfway(low.clone(), high.clone());
+        match target(&mid) {
+            Ordering::Less => {
+                low = mid;
+            }
+            Ordering::Greater => {
+                high = mid;
+            }
+            Ordering::Equal => {
+                return Some(mid);
+            }
+        }
+    }
+
+    Some(halfway(low, high))
+}
+
+/// Gets the value halfway from `low` to `high`, rounded towards `low` for integers.
+/// The width is halved rather than the sum of the values, which may overflow.
+fn halfway<T: Numeric>(low: T, high: T) -> T {
+    let two = T::one() + T::one();
+    low.clone() + (high - low) / two
+}
+
+/// Gets the total length of the intervals without merging them,
+/// so that overlapping parts are counted multiple times.
+/// If any interval is unbounded, returns `None`.
+pub fn total_length_with_overlap<T: Numeric>(intervals: &[Interval<T>]) -> Option<T> {
+    intervals
+        .iter()
+        .try_fold(T::zero(), |total, interval| Some(total + interval.measure()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let interval = Interval::<BigInt>::closed_open(BigInt::from(-3), BigInt::from(7)).unwrap();
+        assert_eq!(interval.measure(), Some(BigInt::from(10)));
+
+        let set = IntervalSet::from(interval);
+        let other = IntervalSet::from(Interval::closed(BigInt::from(5), BigInt::from(9)).unwrap());
+        assert_eq!(set.overlap_measure(&other), Some(BigInt::from(2)));
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let interval = Interval::<i32>::closed(0, 10).unwrap();
+        assert_eq!(interval.midpoint(), Some(5));
+
+        let interval = Interval::<i64>::open(-4, 0).unwrap();
+        assert_eq!(interval.midpoint(), Some(-2));
+
+        let interval = Interval::<f64>::closed(1.0, 2.0).unwrap();
+        assert_eq!(interval.midpoint(), Some(1.5));
+
+        let interval = Interval::<f32>::unbounded_open(1.0);
+        assert_eq!(interval.midpoint(), None);
+
+        let interval = Interval::<BigInt>::closed(BigInt::from(2), BigInt::from(8)).unwrap();
+        assert_eq!(interval.midpoint(), Some(BigInt::from(5)));
+
+        // The sum of the values would overflow
+        let interval = Interval::<i32>::closed(i32::MAX - 1, i32::MAX).unwrap();
+        assert_eq!(interval.midpoint(), Some(i32::MAX - 1));
+
+        let interval = Interval::<i32>::closed(-3, 0).unwrap();
+        assert_eq!(interval.midpoint(), Some(-2));
+    }
+
+    #[test]
+    fn test_sample_points() {
+        let interval = Interval::<i32>::closed(0, 10).unwrap();
+        assert_eq!(interval.sample_points(6), Some(vec![0, 2, 4, 6, 8, 10]));
+
+        // Values of open endpoints are included
+        let interval = Interval::<f64>::open(1.0, 2.0).unwrap();
+        assert_eq!(interval.sample_points(5), Some(vec![1.0, 1.25, 1.5, 1.75, 2.0]));
+        assert_eq!(interval.sample_points(1), Some(vec![1.0]));
+        assert_eq!(interval.sample_points(0), Some(vec![]));
+
+        let interval = Interval::<i32>::closed_unbounded(0);
+        assert_eq!(interval.sample_points(3), None);
+
+        // Multiplying the width would overflow
+        let interval = Interval::<i32>::closed(0, i32::MAX).unwrap();
+        assert_eq!(interval.sample_points(3), Some(vec![0, i32::MAX / 2, i32::MAX]));
+
+        let interval = Interval::<u8>::closed(10, 250).unwrap();
+        assert_eq!(interval.sample_points(4), Some(vec![10, 90, 170, 250]));
+
+        // More samples than the width, where the remainder times the index would overflow
+        let interval = Interval::<i32>::closed(0, 99_998).unwrap();
+        let points = interval.sample_points(100_000).unwrap();
+        assert_eq!(points.len(), 100_000);
+        assert_eq!(points[..3], [0, 0, 1]);
+        assert_eq!(points[99_999], 99_998);
+        assert!(points.windows(2).all(|pair| pair[1] - pair[0] <= 1));
+
+        // The number of steps does not fit in u8
+        let interval = Interval::<u8>::closed(0, 10).unwrap();
+        assert_eq!(interval.sample_points(300), None);
+        assert_eq!(interval.sample_points(256).map(|points| points.len()), Some(256));
+    }
+
+    #[test]
+    fn test_scale() {
+        let interval = Interval::<i32>::closed_open(1, 2).unwrap();
+        assert_eq!(interval.scale(3), Interval::<i32>::closed_open(3, 6).unwrap());
+
+        // Endpoints swap sides for a negative factor
+        let interval = Interval::<i32>::closed_open(1, 2).unwrap();
+        assert_eq!(interval.scale(-1), Interval::<i32>::open_closed(-2, -1).unwrap());
+
+        let interval = Interval::<f64>::open_unbounded(1.0);
+        assert_eq!(interval.scale(-2.0), Interval::<f64>::unbounded_open(-2.0));
+
+        // Collapses to a single point
+        let interval = Interval::<i32>::open(1, 2).unwrap();
+        assert_eq!(interval.scale(0), Interval::<i32>::closed(0, 0).unwrap());
+
+        let interval = Interval::closed(0.0, 1.0).unwrap();
+        assert!(std::panic::catch_unwind(|| interval.scale(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_scale_all() {
+        let set = IntervalSet {
+            intervals: vec![
+                Interval::<i32>::closed(1, 2).unwrap(),
+                Interval::<i32>::open(3, 4).unwrap()
+            ],
+        };
+
+        let scaled = set.scale_all(2);
+        assert_eq!(
+            scaled.intervals,
+            vec![Interval::<i32>::closed(2, 4).unwrap(), Interval::<i32>::open(6, 8).unwrap()]
+        );
+
+        // The order of the intervals is reversed
+        let scaled = set.scale_all(-1);
+        assert_eq!(
+            scaled.intervals,
+            vec![Interval::<i32>::open(-4, -3).unwrap(), Interval::<i32>::closed(-2, -1).unwrap()]
+        );
+
+        // The intervals collapse to the same point and are merged
+        let scaled = set.scale_all(0);
+        assert_eq!(scaled.intervals, vec![Interval::<i32>::closed(0, 0).unwrap()]);
+
+        // Scaling into subnormals rounds the intervals until they touch, so they are merged
+        let set = IntervalSet::from_intervals(vec![
+            Interval::<f64>::closed_open(0.0, 1.0).unwrap(),
+            Interval::<f64>::closed(1.25, 2.0).unwrap()
+        ]);
+        let factor = f64::from_bits(1);
+        assert_eq!(
+            set.scale_all(factor).intervals,
+            vec![Interval::<f64>::closed(0.0, 2.0 * factor).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_measure() {
+        let set = IntervalSet::from_intervals(vec![Interval::closed(0, 5).unwrap(), Interval::closed(10, 20).unwrap()]);
+
+        // The budget runs out in the middle of the second interval
+        assert_eq!(
+            set.truncate_to_measure(8).intervals,
+            vec![Interval::closed(0, 5).unwrap(), Interval::closed(10, 13).unwrap()]
+        );
+        assert_eq!(set.truncate_to_measure(5).intervals, vec![Interval::closed(0, 5).unwrap()]);
+        assert_eq!(set.truncate_to_measure(2).intervals, vec![Interval::closed(0, 2).unwrap()]);
+        assert_eq!(set.truncate_to_measure(15).intervals, set.intervals);
+        assert_eq!(set.truncate_to_measure(100).intervals, set.intervals);
+        assert!(set.truncate_to_measure(0).intervals.is_empty());
+
+        let set = IntervalSet::from_intervals(vec![Interval::open(0, 5).unwrap(), Interval::open_unbounded(10)]);
+        assert_eq!(
+            set.truncate_to_measure(7).intervals,
+            vec![Interval::open(0, 5).unwrap(), Interval::open_closed(10, 12).unwrap()]
+        );
+
+        let set = IntervalSet::from_intervals(vec![Interval::unbounded_closed(0)]);
+        assert!(set.truncate_to_measure(7).intervals.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_by_measure() {
+        let set = IntervalSet::from_intervals(vec![Interval::closed(0, 10).unwrap()]);
+        let chunks: Vec<Vec<Interval<i32>>> = set
+            .chunk_by_measure(3)
+            .into_iter()
+            .map(|chunk| chunk.intervals)
+            .collect();
+        assert_eq!(
+            chunks,
+            vec![
+                vec![Interval::closed(0, 3).unwrap()],
+                vec![Interval::open_closed(3, 6).unwrap()],
+                vec![Interval::open_closed(6, 9).unwrap()],
+                vec![Interval::open_closed(9, 10).unwrap()]
+            ]
+        );
+
+        // A chunk may span several intervals
+        let set = IntervalSet::from_intervals(vec![Interval::closed(0, 2).unwrap(), Interval::closed(5, 9).unwrap()]);
+        let chunks: Vec<Vec<Interval<i32>>> = set
+            .chunk_by_measure(3)
+            .into_iter()
+            .map(|chunk| chunk.intervals)
+            .collect();
+        assert_eq!(
+            chunks,
+            vec![
+                vec![Interval::closed(0, 2).unwrap(), Interval::closed(5, 6).unwrap()],
+                vec![Interval::open_closed(6, 9).unwrap()]
+            ]
+        );
+
+        assert!(set.chunk_by_measure(0).is_empty());
+        assert!(IntervalSet::from_intervals(vec![Interval::at_least(0)]).chunk_by_measure(3).is_empty());
+
+        // Adding the chunk size to 1e16 is lost to rounding
+        let set = IntervalSet::from_intervals([Interval::closed(1e16, 1e16 + 100.0).unwrap()]);
+        let chunks: Vec<Vec<Interval<f64>>> = set
+            .chunk_by_measure(1.0)
+            .into_iter()
+            .map(|chunk| chunk.intervals)
+            .collect();
+        assert_eq!(
+            chunks,
+            vec![
+                vec![Interval::closed(1e16, 1e16).unwrap()],
+                vec![Interval::open_closed(1e16, 1e16 + 100.0).unwrap()]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_total_length_with_overlap() {
+        let intervals = vec![Interval::<i32>::closed(0, 4).unwrap(), Interval::closed(2, 6).unwrap()];
+        assert_eq!(total_length_with_overlap(&intervals), Some(8));
+
+        let set = IntervalSet::union_all(intervals.into_iter().map(IntervalSet::from));
+        assert_eq!(set.measure(), Some(6));
+
+        let intervals = vec![Interval::<i32>::closed(0, 4).unwrap(), Interval::closed_unbounded(2)];
+        assert_eq!(total_length_with_overlap(&intervals), None);
+        assert_eq!(total_length_with_overlap::<i32>(&[]), Some(0));
+    }
+
+    #[test]
+    fn test_bisect() {
+        let interval = Interval::<f64>::closed(1.0, 2.0).unwrap();
+        let root = bisect(&interval, |x| (x * x).partial_cmp(&2.0).unwrap(), 40).unwrap();
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-9);
+
+        let interval = Interval::<i32>::closed(0, 100).unwrap();
+        assert_eq!(bisect(&interval, |x| x.cmp(&37), 10), Some(37));
+
+        let interval = Interval::<f64>::closed_unbounded(1.0);
+        assert_eq!(bisect(&interval, |x| x.partial_cmp(&2.0).unwrap(), 10), None);
+
+        // The sum of the bounds would overflow near the maximum
+        let interval = Interval::<i32>::closed(i32::MAX - 10, i32::MAX).unwrap();
+        assert_eq!(bisect(&interval, |x| x.cmp(&(i32::MAX - 1)), 10), Some(i32::MAX - 1));
+        assert_eq!(bisect(&interval, |x| x.cmp(&i32::MAX), 2), Some(i32::MAX - 2));
+    }
+
+    #[test]
+    fn test_to_ascii_art() {
+        let set = IntervalSet::<i32>::closed(2, 4)
+            .unwrap()
+            .union(&IntervalSet::open(6, 8).unwrap());
+        assert_eq!(set.to_ascii_art(0, 10, 11), "..###..#...");
+
+        let set = IntervalSet::<f64>::closed_open(0.0, 0.5).unwrap();
+        assert_eq!(set.to_ascii_art(0.0, 1.0, 5), "##...");
+        assert_eq!(set.to_ascii_art(1.0, 0.0, 5), "");
+    }
+
+    #[test]
+    fn test_change_ratio() {
+        let set = IntervalSet::<i32>::closed(0, 8)
+            .unwrap()
+            .union(&IntervalSet::closed(10, 12).unwrap());
+        assert_eq!(set.change_ratio(&set), Some(0.0));
+
+        // Shifted by one
+        let shifted = set.translate_all(1);
+        assert_eq!(set.change_ratio(&shifted), Some(4.0 / 12.0));
+
+        let other = IntervalSet::<i32>::closed(20, 30).unwrap();
+        assert_eq!(set.change_ratio(&other), Some(1.0));
+
+        let other = IntervalSet::<i32>::closed_unbounded(20);
+        assert_eq!(set.change_ratio(&other), None);
+    }
+
+    #[test]
+    fn test_to_grid_cells() {
+        let set = IntervalSet::<i32>::closed(3, 12).unwrap();
+        assert_eq!(set.to_grid_cells(5, 0).unwrap().intervals, vec![Interval::closed_open(0, 15).unwrap()]);
+
+        // Two nearby ranges snap to the same cell and merge into one block
+        let set = IntervalSet::<i32>::closed(1, 3)
+            .unwrap()
+            .union(&IntervalSet::open(6, 8).unwrap())
+            .union(&IntervalSet::closed(21, 22).unwrap());
+        assert_eq!(
+            set.to_grid_cells(5, 1).unwrap().intervals,
+            vec![Interval::closed_open(1, 11).unwrap(), Interval::closed_open(21, 26).unwrap()]
+        );
+
+        // Negative points and unbounded sides
+        let set = IntervalSet::<i32>::unbounded_closed(-7).union(&IntervalSet::open_unbounded(-1));
+        assert_eq!(
+            set.to_grid_cells(5, 0).unwrap().intervals,
+            vec![Interval::unbounded_open(-5), Interval::closed_unbounded(0)]
+        );
+
+        let set = IntervalSet::<i32>::open(0, 1).unwrap();
+        assert!(set.to_grid_cells(5, 0).unwrap().intervals.is_empty());
+        // The cell boundaries would overflow
+        let set = IntervalSet::<i32>::closed(i32::MAX - 2, i32::MAX).unwrap();
+        assert_eq!(set.to_grid_cells(5, 0), Err(IntervalSetError::GridCellOverflow));
+
+        let set = IntervalSet::<i32>::closed(i32::MIN, i32::MIN + 2).unwrap();
+        assert_eq!(set.to_grid_cells(5, 0), Err(IntervalSetError::GridCellOverflow));
+
+        let set = IntervalSet::<i32>::closed(-10, 10).unwrap();
+        assert_eq!(set.to_grid_cells(5, i32::MAX), Err(IntervalSetError::GridCellOverflow));
+
+        // The cells fit exactly
+        let set = IntervalSet::<i8>::closed(0, 126).unwrap();
+        assert_eq!(
+            set.to_grid_cells(1, 0).unwrap().intervals,
+            vec![Interval::closed_open(0, 127).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_overlap_measure() {
+        let set = IntervalSet::<i32>::closed(9, 12)
+            .unwrap()
+            .union(&IntervalSet::closed(14, 18).unwrap());
+        let other = IntervalSet::<i32>::closed(11, 15).unwrap();
+
+        // [11, 12] and [14, 15] conflict
+        assert_eq!(set.overlap_measure(&other), Some(2));
+
+        let set = IntervalSet::<f64>::closed_unbounded(0.0);
+        let other = IntervalSet::<f64>::open_unbounded(1.0);
+        assert_eq!(set.overlap_measure(&other), None);
+    }
+
+    #[test]
+    fn test_jaccard() {
+        let set = IntervalSet::<i32>::closed(0, 4)
+            .unwrap()
+            .union(&IntervalSet::closed(6, 8).unwrap());
+        assert_eq!(set.jaccard(&set), Some(1.0));
+
+        let other = IntervalSet::<i32>::closed(10, 12).unwrap();
+        assert_eq!(set.jaccard(&other), Some(0.0));
+
+        let set = IntervalSet::<f64>::closed(0.0, 2.0).unwrap();
+        let other = IntervalSet::<f64>::closed(1.0, 3.0).unwrap();
+        assert_eq!(set.jaccard(&other), Some(1.0 / 3.0));
+
+        let other = IntervalSet::<f64>::closed_unbounded(1.0);
+        assert_eq!(set.jaccard(&other), None);
+    }
+
+    #[test]
+    fn test_covers_at_least() {
+        let set = IntervalSet::<i32>::closed(0, 4)
+            .unwrap()
+            .union(&IntervalSet::open(10, 14).unwrap())
+            .union(&IntervalSet::closed_unbounded(100));
+        let window = Interval::closed(2, 12).unwrap();
+
+        // 2, 3, 4, 11 and 12 are covered
+        assert!(set.covers_at_least(&window, 5));
+        assert!(!set.covers_at_least(&window, 6));
+        assert!(set.covers_at_least(&window, 0));
+
+        // The threshold is reached early in the unbounded part
+        assert!(set.covers_at_least(&Interval::closed_unbounded(50), 1_000_000));
+
+        let set = IntervalSet::<i8>::closed(i8::MIN, i8::MAX).unwrap();
+        assert!(set.covers_at_least(&Interval::universe(), 256));
+        assert!(!set.covers_at_least(&Interval::universe(), 257));
+
+        let set = IntervalSet::<u8>::open(0, 1).unwrap();
+        assert!(!set.covers_at_least(&Interval::universe(), 1));
+    }
+
+    #[test]
+    fn test_iter_points() {
+        let points = |interval: Interval<i32>| interval.iter_points().collect::<Vec<_>>();
+
+        assert_eq!(points(Interval::open_closed(0, 5).unwrap()), vec![1, 2, 3, 4, 5]);
+        assert_eq!(points(Interval::closed_open(0, 3).unwrap()), vec![0, 1, 2]);
+        assert_eq!(points(Interval::closed(-1, 1).unwrap()), vec![-1, 0, 1]);
+        assert_eq!(points(Interval::open(0, 3).unwrap()), vec![1, 2]);
+        assert_eq!(points(Interval::closed(4, 4).unwrap()), vec![4]);
+        assert!(points(Interval::open(0, 1).unwrap()).is_empty());
+        assert!(points(Interval::closed_unbounded(0)).is_empty());
+
+        // The endpoints at the limits of the type do not overflow
+        let interval = Interval::<u8>::closed(253, u8::MAX).unwrap();
+        assert_eq!(interval.iter_points().collect::<Vec<_>>(), vec![253, 254, 255]);
+        let interval = Interval::<i8>::open(i8::MIN, -126).unwrap();
+        assert_eq!(interval.iter_points().collect::<Vec<_>>(), vec![-127]);
+    }
+
+    #[test]
+    fn test_iter_points_modular() {
+        let interval = Interval::<i32>::closed_open(22, 26).unwrap();
+        assert_eq!(interval.iter_points_modular(24).collect::<Vec<_>>(), vec![22, 23, 0, 1]);
+
+        let interval = Interval::<i32>::closed(22, 26).unwrap();
+        assert_eq!(interval.iter_points_modular(24).collect::<Vec<_>>(), vec![22, 23, 0, 1, 2]);
+
+        let interval = Interval::<u32>::open_closed(3, 6).unwrap();
+        assert_eq!(interval.iter_points_modular(24).collect::<Vec<_>>(), vec![4, 5, 6]);
+
+        let interval = Interval::<i32>::closed_open(-2, 1).unwrap();
+        assert_eq!(interval.iter_points_modular(24).collect::<Vec<_>>(), vec![22, 23, 0]);
+
+        let interval = Interval::<i32>::open(0, 1).unwrap();
+        assert_eq!(interval.iter_points_modular(24).count(), 0);
+
+        let interval = Interval::<i32>::closed_unbounded(0);
+        assert_eq!(interval.iter_points_modular(24).count(), 0);
+
+        // The modulus must be positive even if nothing would be yielded
+        let interval = Interval::<i32>::closed(0, 3).unwrap();
+        assert!(std::panic::catch_unwind(|| interval.iter_points_modular(0).count()).is_err());
+        assert!(std::panic::catch_unwind(|| interval.iter_points_modular(-24).count()).is_err());
+        let interval = Interval::<u32>::closed_unbounded(0);
+        assert!(std::panic::catch_unwind(|| interval.iter_points_modular(0).count()).is_err());
+    }
+}
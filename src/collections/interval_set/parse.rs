@@ -0,0 +1,125 @@
+use std::str::FromStr;
+use super::{ Endpoint, Interval, IntervalSet, IntervalSetError, IntervalSetResult };
+
+/// Parses the interval from the format produced by its `Display` implementation,
+/// e.g., `[0, 1)`, `[2]`, `(-∞, 5)` and `[0, +∞)`.
+/// A well-formed but invalid interval such as `(1, 0)` is rejected
+/// with [`IntervalSetError::InvalidInterval`].
+impl<T: FromStr + PartialOrd + Clone> FromStr for Interval<T> {
+    type Err = IntervalSetError;
+
+    fn from_str(s: &str) -> IntervalSetResult<Self> {
+        let error = || IntervalSetError::ParseError(s.to_string());
+
+        let s = s.trim();
+        let mut chars = s.chars();
+        let (Some(opening), Some(closing)) = (chars.next(), chars.next_back()) else {
+            return Err(error());
+        };
+        let inner = chars.as_str();
+
+        let value = |token: &str| token.trim().parse::<T>().map_err(|_| error());
+
+        // A degenerate interval such as `[2]` has a single value
+        let Some((low, high)) = inner.split_once(',') else {
+            return match (opening, closing) {
+                ('[', ']') => {
+                    let value = value(inner)?;
+                    Interval::closed(value.clone(), value)
+                }
+                _ => Err(error()),
+            };
+        };
+
+        let left = match (opening, low.trim()) {
+            ('(', "-∞") => Endpoint::Unbounded,
+            ('(', low) => Endpoint::Open(value(low)?),
+            ('[', low) => Endpoint::Closed(value(low)?),
+            _ => {
+                return Err(error());
+            }
+        };
+
+        let right = match (closing, high.trim()) {
+            (')', "+∞") => Endpoint::Unbounded,
+            (')', high) => Endpoint::Open(value(high)?),
+            (']', high) => Endpoint::Closed(value(high)?),
+            _ => {
+                return Err(error());
+            }
+        };
+
+        Interval::new(left, right)
+    }
+}
+
+/// Parses the set from the format produced by its `Display` implementation,
+/// i.e., intervals separated by `∪`, or `∅` for the empty set.
+/// The intervals may be given in any order and may overlap.
+impl<T: FromStr + PartialOrd + Clone> FromStr for IntervalSet<T> {
+    type Err = IntervalSetError;
+
+    fn from_str(s: &str) -> IntervalSetResult<Self> {
+        if s.trim() == "∅" {
+            return Ok(Self::empty());
+        }
+
+        let intervals = s
+            .split('∪')
+            .map(str::parse)
+            .collect::<IntervalSetResult<Vec<_>>>()?;
+
+        Self::try_from_intervals(intervals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_interval() {
+        assert_eq!("(0, 1)".parse::<Interval<i32>>(), Interval::open(0, 1));
+        assert_eq!("[0, 1]".parse::<Interval<i32>>(), Interval::closed(0, 1));
+        assert_eq!(" (0,1] ".parse::<Interval<i32>>(), Interval::open_closed(0, 1));
+        assert_eq!("[2]".parse::<Interval<i32>>(), Interval::closed(2, 2));
+        assert_eq!("[0, +∞)".parse::<Interval<i32>>(), Ok(Interval::closed_unbounded(0)));
+        assert_eq!("(-∞, 5)".parse::<Interval<i32>>(), Ok(Interval::unbounded_open(5)));
+        assert_eq!("(-∞, +∞)".parse::<Interval<f64>>(), Ok(Interval::universe()));
+        assert_eq!("[-1.5, 2.25)".parse::<Interval<f64>>(), Interval::closed_open(-1.5, 2.25));
+
+        assert_eq!("(1, 0)".parse::<Interval<i32>>(), Err(IntervalSetError::InvalidInterval));
+        for s in ["", "(", "0, 1", "[0, 1", "(2)", "[-∞, 0)", "(0, +∞]", "[a, 1]", "[0, 1, 2]"] {
+            assert_eq!(s.parse::<Interval<i32>>(), Err(IntervalSetError::ParseError(s.to_string())));
+        }
+    }
+
+    #[test]
+    fn parse_interval_set() {
+        let set = "[0, 1) ∪ (2, 3] ∪ [5]".parse::<IntervalSet<i32>>().unwrap();
+        assert_eq!(set.to_string(), "[0, 1) ∪ (2, 3] ∪ [5]");
+
+        // Unsorted and overlapping intervals are normalized
+        let set = "[4, 6] ∪ [0, 1] ∪ (1, 5)".parse::<IntervalSet<i32>>().unwrap();
+        assert_eq!(set, IntervalSet::closed(0, 6).unwrap());
+
+        assert!("∅".parse::<IntervalSet<i32>>().unwrap().is_empty());
+        assert_eq!("[0, 1] ∪ (1, 0)".parse::<IntervalSet<i32>>(), Err(IntervalSetError::InvalidInterval));
+        assert!("[0, 1] ∪".parse::<IntervalSet<i32>>().is_err());
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let sets = vec![
+            IntervalSet::empty(),
+            IntervalSet::closed(0, 0).unwrap(),
+            IntervalSet::unbounded_open(-3).union(&IntervalSet::open_closed(0, 2).unwrap()),
+            IntervalSet::closed_unbounded(10).union(&IntervalSet::closed_open(-5, 5).unwrap()),
+            IntervalSet::from(Interval::universe())
+        ];
+
+        for s in sets {
+            assert_eq!(s.to_string().parse::<IntervalSet<i32>>().unwrap(), s);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use rand::Rng;
+use rand::distributions::WeightedIndex;
+use rand::distributions::uniform::SampleUniform;
+use super::{ Endpoint, IntervalSet, IntervalSetError, IntervalSetResult };
+
+/// The maximum number of draws before giving up on an interval,
+/// since a draw may land on an open endpoint.
+const MAX_ATTEMPTS: usize = 64;
+
+impl<T: PartialOrd + Clone + SampleUniform> IntervalSet<T> {
+    /// Samples a point of the set, where each interval is chosen
+    /// with the probability proportional to its weight rather than its length,
+    /// and the point is then drawn uniformly from the chosen interval.
+    /// Returns an error if the number of weights differs from the number of intervals.
+    /// Returns `None` if the weights are all zero or invalid, if the chosen interval is unbounded,
+    /// or if no point could be drawn, e.g., from `(0, 1)` of integers.
+    pub fn weighted_sample<R: Rng>(
+        &self,
+        weights: &[f64],
+        rng: &mut R
+    ) -> IntervalSetResult<Option<T>> {
+        if weights.len() != self.intervals.len() {
+            return Err(IntervalSetError::WeightCountMismatch {
+                expected: self.intervals.len(),
+                actual: weights.len(),
+            });
+        }
+
+        let Ok(distribution) = WeightedIndex::new(weights) else {
+            return Ok(None);
+        };
+        let interval = &self.intervals[rng.sample(distribution)];
+
+        let (Some(low), Some(high)) = (interval.low(), interval.high()) else {
+            return Ok(None);
+        };
+
+        for _ in 0..MAX_ATTEMPTS {
+            let point = match interval.right() {
+                Endpoint::Open(_) if low < high => rng.gen_range(low.clone()..high.clone()),
+                _ => rng.gen_range(low.clone()..=high.clone()),
+            };
+
+            if interval.contains(&point) {
+                return Ok(Some(point));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use super::*;
+    use super::super::Interval;
+
+    #[test]
+    fn test_weighted_sample() {
+        let set = IntervalSet::closed(0.0, 1.0)
+            .unwrap()
+            .union(&IntervalSet::closed(10.0, 100.0).unwrap());
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // The short interval is chosen far more often despite its length
+        let draws = 10000;
+        let mut hits = 0;
+        for _ in 0..draws {
+            let point = set.weighted_sample(&[9.0, 1.0], &mut rng).unwrap().unwrap();
+            assert!(set.intervals.iter().any(|interval| interval.contains(&point)));
+            if point <= 1.0 {
+                hits += 1;
+            }
+        }
+        assert!((8500..9500).contains(&hits));
+
+        let point = set.weighted_sample(&[0.0, 1.0], &mut rng).unwrap().unwrap();
+        assert!(point >= 10.0);
+        assert_eq!(set.weighted_sample(&[0.0, 0.0], &mut rng), Ok(None));
+    }
+
+    #[test]
+    fn weight_count_mismatch() {
+        let set = IntervalSet::from(Interval::closed_open(0, 10).unwrap());
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(
+            set.weighted_sample(&[1.0, 2.0], &mut rng),
+            Err(IntervalSetError::WeightCountMismatch { expected: 1, actual: 2 })
+        );
+
+        let point = set.weighted_sample(&[1.0], &mut rng).unwrap().unwrap();
+        assert!((0..10).contains(&point));
+    }
+}
@@ -0,0 +1,113 @@
+use roaring::RoaringBitmap;
+use super::{ Endpoint, Interval, IntervalSet };
+
+impl IntervalSet<u32> {
+    /// Converts the set to a bitmap of the integers it contains.
+    /// If the set is unbounded, returns `None`.
+    pub fn to_roaring(&self) -> Option<RoaringBitmap> {
+        let mut bitmap = RoaringBitmap::new();
+
+        for interval in &self.intervals {
+            // Get the least and the greatest integers in the interval
+            let start = match interval.left() {
+                Endpoint::Open(low) => low.checked_add(1),
+                Endpoint::Closed(low) => Some(*low),
+                Endpoint::Unbounded => {
+                    return None;
+                }
+            };
+            let end = match interval.right() {
+                Endpoint::Open(high) => high.checked_sub(1),
+                Endpoint::Closed(high) => Some(*high),
+                Endpoint::Unbounded => {
+                    return None;
+                }
+            };
+
+            if let (Some(start), Some(end)) = (start, end) {
+                if start <= end {
+                    bitmap.insert_range(start..=end);
+                }
+            }
+        }
+
+        Some(bitmap)
+    }
+
+    /// Creates a set from a bitmap,
+    /// where each run of consecutive integers becomes a closed interval.
+    pub fn from_roaring(bitmap: &RoaringBitmap) -> Self {
+        let mut intervals = Vec::new();
+
+        // Start and end of the current run
+        let mut run: Option<(u32, u32)> = None;
+
+        for value in bitmap {
+            run = match run {
+                Some((start, end)) if end + 1 == value => Some((start, value)),
+                Some((start, end)) => {
+                    // It is safe to unwrap since start <= end
+                    intervals.push(Interval::closed(start, end).unwrap());
+                    Some((value, value))
+                }
+                None => Some((value, value)),
+            };
+        }
+
+        if let Some((start, end)) = run {
+            intervals.push(Interval::closed(start, end).unwrap());
+        }
+
+        // The runs are already sorted and separated
+        Self { intervals }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let set = IntervalSet::<u32>::try_from_intervals(vec![
+            Interval::closed(0, 3).unwrap(),
+            Interval::closed(10, 10).unwrap(),
+            Interval::closed(20, 1000).unwrap()
+        ]).unwrap();
+        let bitmap = set.to_roaring().unwrap();
+        assert_eq!(bitmap.len(), 4 + 1 + 981);
+        assert_eq!(IntervalSet::from_roaring(&bitmap).intervals, set.intervals);
+
+        // Many small gaps
+        let set = IntervalSet::<u32>::try_from_intervals(
+            (0..1000).map(|k| Interval::closed(3 * k, 3 * k + 1).unwrap()).collect()
+        ).unwrap();
+        let bitmap = set.to_roaring().unwrap();
+        assert_eq!(bitmap.len(), 2000);
+        assert_eq!(IntervalSet::from_roaring(&bitmap).intervals, set.intervals);
+    }
+
+    #[test]
+    fn test_open_endpoints() {
+        let set = IntervalSet::<u32>::try_from_intervals(vec![
+            Interval::open(0, 3).unwrap(),
+            Interval::open(5, 6).unwrap(),
+            Interval::open_closed(6, 8).unwrap()
+        ]).unwrap();
+        let bitmap = set.to_roaring().unwrap();
+        assert_eq!(bitmap.iter().collect::<Vec<u32>>(), vec![1, 2, 7, 8]);
+        assert_eq!(
+            IntervalSet::from_roaring(&bitmap).intervals,
+            vec![Interval::closed(1, 2).unwrap(), Interval::closed(7, 8).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let set = IntervalSet::<u32>::closed_unbounded(5);
+        assert!(set.to_roaring().is_none());
+
+        let set = IntervalSet::from_roaring(&RoaringBitmap::new());
+        assert!(set.intervals.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+use serde::de::Error;
+use super::{ Endpoint, Interval, IntervalSet };
+
+/// The serialized form of an endpoint, tagged by its kind,
+/// e.g., `{"kind": "open", "value": 0}` or `{"kind": "unbounded"}`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "lowercase")]
+enum EndpointRepr<T> {
+    Open(T),
+    Closed(T),
+    Unbounded,
+}
+
+/// The serialized form of an interval.
+#[derive(Serialize, Deserialize)]
+struct IntervalRepr<T> {
+    left: EndpointRepr<T>,
+    right: EndpointRepr<T>,
+}
+
+impl<T: PartialOrd + Clone> From<Endpoint<T>> for EndpointRepr<T> {
+    fn from(endpoint: Endpoint<T>) -> Self {
+        match endpoint {
+            Endpoint::Open(value) => EndpointRepr::Open(value),
+            Endpoint::Closed(value) => EndpointRepr::Closed(value),
+            Endpoint::Unbounded => EndpointRepr::Unbounded,
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> From<EndpointRepr<T>> for Endpoint<T> {
+    fn from(endpoint: EndpointRepr<T>) -> Self {
+        match endpoint {
+            EndpointRepr::Open(value) => Endpoint::Open(value),
+            EndpointRepr::Closed(value) => Endpoint::Closed(value),
+            EndpointRepr::Unbounded => Endpoint::Unbounded,
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone + Serialize> Serialize for Endpoint<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EndpointRepr::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de, T: PartialOrd + Clone + Deserialize<'de>> Deserialize<'de> for Endpoint<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        EndpointRepr::deserialize(deserializer).map(Endpoint::from)
+    }
+}
+
+impl<T: PartialOrd + Clone + Serialize> Serialize for Interval<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        IntervalRepr {
+            left: EndpointRepr::from(self.left().clone()),
+            right: EndpointRepr::from(self.right().clone()),
+        }.serialize(serializer)
+    }
+}
+
+/// The endpoints are validated as in [`Interval::new`],
+/// so that malformed data such as `left > right` fails to deserialize.
+impl<'de, T: PartialOrd + Clone + Deserialize<'de>> Deserialize<'de> for Interval<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let IntervalRepr { left, right } = IntervalRepr::deserialize(deserializer)?;
+        Interval::new(left.into(), right.into()).map_err(D::Error::custom)
+    }
+}
+
+/// The set is serialized as an array of its intervals.
+impl<T: PartialOrd + Clone + Serialize> Serialize for IntervalSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.intervals)
+    }
+}
+
+/// The intervals may be unsorted and overlapping,
+/// and they are normalized as in [`IntervalSet::try_from_intervals`].
+impl<'de, T: PartialOrd + Clone + Deserialize<'de>> Deserialize<'de> for IntervalSet<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let intervals = Vec::<Interval<T>>::deserialize(deserializer)?;
+        IntervalSet::try_from_intervals(intervals).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip() {
+        let set = IntervalSet::unbounded_open(-3).union(&IntervalSet::closed_open(0, 2).unwrap());
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"left":{"kind":"unbounded"},"right":{"kind":"open","value":-3}},{"left":{"kind":"closed","value":0},"right":{"kind":"open","value":2}}]"#
+        );
+        assert_eq!(serde_json::from_str::<IntervalSet<i32>>(&json).unwrap(), set);
+
+        let interval = Interval::open_closed(0.5, 1.5).unwrap();
+        let json = serde_json::to_string(&interval).unwrap();
+        assert_eq!(serde_json::from_str::<Interval<f64>>(&json).unwrap(), interval);
+
+        let empty = IntervalSet::<i32>::empty();
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+        assert!(serde_json::from_str::<IntervalSet<i32>>("[]").unwrap().is_empty());
+    }
+
+    #[test]
+    fn deserialize_invalid_interval() {
+        let json = r#"{"left":{"kind":"closed","value":2},"right":{"kind":"closed","value":1}}"#;
+        assert!(serde_json::from_str::<Interval<i32>>(json).is_err());
+
+        let json = r#"[{"left":{"kind":"open","value":1},"right":{"kind":"open","value":1}}]"#;
+        assert!(serde_json::from_str::<IntervalSet<i32>>(json).is_err());
+
+        let json = r#"{"left":{"kind":"half-open","value":0},"right":{"kind":"unbounded"}}"#;
+        assert!(serde_json::from_str::<Interval<i32>>(json).is_err());
+    }
+
+    #[test]
+    fn deserialize_normalizes_set() {
+        let json = r#"[
+            {"left":{"kind":"closed","value":4},"right":{"kind":"closed","value":6}},
+            {"left":{"kind":"closed","value":0},"right":{"kind":"open","value":5}}
+        ]"#;
+        let set = serde_json::from_str::<IntervalSet<i32>>(json).unwrap();
+        assert_eq!(set, IntervalSet::closed(0, 6).unwrap());
+    }
+}
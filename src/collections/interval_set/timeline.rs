@@ -0,0 +1,113 @@
+use super::{ Interval, IntervalSet };
+
+/// A timeline of busy periods booked within a fixed horizon.
+#[derive(Debug, Clone)]
+pub struct Timeline<T: PartialOrd + Clone> {
+    /// The period in which slots can be booked.
+    horizon: Interval<T>,
+
+    /// The booked periods.
+    busy: IntervalSet<T>,
+}
+
+/// The reason why a slot cannot be booked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conflict<T: PartialOrd + Clone> {
+    /// The slot is not within the horizon.
+    OutOfHorizon,
+
+    /// The slot overlaps the booked period.
+    Overlap(Interval<T>),
+}
+
+impl<T: PartialOrd + Clone> Timeline<T> {
+    /// Creates a new timeline with no bookings.
+    pub fn new(horizon: Interval<T>) -> Self {
+        Self {
+            horizon,
+            busy: IntervalSet::empty(),
+        }
+    }
+
+    /// Gets the horizon.
+    pub fn horizon(&self) -> &Interval<T> {
+        &self.horizon
+    }
+
+    /// Gets the booked periods.
+    pub fn busy_slots(&self) -> &IntervalSet<T> {
+        &self.busy
+    }
+
+    /// Books the slot.
+    /// Returns an error if the slot is not within the horizon or
+    /// it overlaps any existing booking.
+    pub fn book(&mut self, slot: Interval<T>) -> Result<(), Conflict<T>> {
+        // The slot is within the horizon if and only if
+        // its intersection with the horizon is the slot itself
+        if self.horizon.intersection(&slot).as_ref() != Some(&slot) {
+            return Err(Conflict::OutOfHorizon);
+        }
+
+        if let Some(booked) = self.busy.intervals
+            .iter()
+            .find(|booked| booked.overlaps(&slot))
+        {
+            return Err(Conflict::Overlap(booked.clone()));
+        }
+
+        self.busy.intervals.push(slot);
+        self.busy.normalize();
+
+        Ok(())
+    }
+
+    /// Gets the free periods within the horizon.
+    pub fn free_slots(&self) -> IntervalSet<T> {
+        IntervalSet::from(self.horizon.clone()).difference(&self.busy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_book() {
+        let mut timeline = Timeline::new(Interval::<i32>::closed_open(9, 17).unwrap());
+
+        assert!(timeline.book(Interval::closed_open(9, 10).unwrap()).is_ok());
+        assert!(timeline.book(Interval::closed_open(13, 15).unwrap()).is_ok());
+        assert!(timeline.book(Interval::closed_open(10, 11).unwrap()).is_ok());
+
+        // Overlaps an existing booking
+        assert_eq!(
+            timeline.book(Interval::closed_open(14, 16).unwrap()),
+            Err(Conflict::Overlap(Interval::closed_open(13, 15).unwrap()))
+        );
+
+        // Out of the horizon
+        assert_eq!(
+            timeline.book(Interval::closed_open(16, 18).unwrap()),
+            Err(Conflict::OutOfHorizon)
+        );
+
+        assert_eq!(
+            timeline.busy_slots().intervals,
+            vec![Interval::closed_open(9, 11).unwrap(), Interval::closed_open(13, 15).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_free_slots() {
+        let mut timeline = Timeline::new(Interval::<i32>::closed_open(9, 17).unwrap());
+        assert_eq!(timeline.free_slots().intervals, vec![Interval::closed_open(9, 17).unwrap()]);
+
+        timeline.book(Interval::closed_open(10, 12).unwrap()).unwrap();
+        timeline.book(Interval::closed_open(15, 17).unwrap()).unwrap();
+        assert_eq!(
+            timeline.free_slots().intervals,
+            vec![Interval::closed_open(9, 10).unwrap(), Interval::closed_open(12, 15).unwrap()]
+        );
+    }
+}
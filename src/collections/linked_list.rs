@@ -1,9 +1,10 @@
 #[derive(Debug)]
 pub struct LinkedList<T> {
-    pub value: Option<T>,
-    pub next: Option<Box<LinkedList<T>>>,
+    head: Option<Box<LinkedListNode<T>>>,
+    len: usize,
 }
 
+#[derive(Debug)]
 pub struct LinkedListNode<T> {
     pub value: T,
     pub next: Option<Box<LinkedListNode<T>>>,
@@ -11,10 +12,159 @@ pub struct LinkedListNode<T> {
 
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
-        Self {
-            value: None,
-            next: None,
+        Self { head: None, len: 0 }
+    }
+
+    /// Gets the number of values in the list in O(1) time.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Creates a list with the values of the vector in the same order.
+    pub fn from_vec(values: Vec<T>) -> Self {
+        let mut list = Self::new();
+
+        // Pushing to the front in reverse avoids walking to the tail each time
+        for value in values.into_iter().rev() {
+            list.push_front(value);
+        }
+
+        list
+    }
+
+    /// Adds the value to the front of the list in O(1) time.
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(LinkedListNode { value, next }));
+        self.len += 1;
+    }
+
+    /// Adds the value to the back of the list.
+    /// This takes O(n) time since it walks to the tail.
+    pub fn push_back(&mut self, value: T) {
+        // Find the empty link after the tail
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+
+        *link = Some(Box::new(LinkedListNode { value, next: None }));
+        self.len += 1;
+    }
+
+    /// Removes the value at the front of the list in O(1) time.
+    /// Returns `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head.take()?;
+        self.head = node.next;
+        self.len -= 1;
+
+        Some(node.value)
+    }
+
+    /// Removes the value at the back of the list.
+    /// This takes O(n) time since it walks to the tail.
+    /// Returns `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        // Find the link to the tail
+        let mut link = &mut self.head;
+        while link.as_ref()?.next.is_some() {
+            link = &mut link.as_mut()?.next;
+        }
+
+        let node = link.take()?;
+        self.len -= 1;
+
+        Some(node.value)
+    }
+
+    /// Gets the value at the index, counting from the head.
+    /// This takes O(n) time since it walks the chain.
+    /// Returns `None` if the index is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut node = self.head.as_deref()?;
+        for _ in 0..index {
+            node = node.next.as_deref()?;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Gets the mutable value at the index, counting from the head.
+    /// This takes O(n) time since it walks the chain.
+    /// Returns `None` if the index is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut node = self.head.as_deref_mut()?;
+        for _ in 0..index {
+            node = node.next.as_deref_mut()?;
+        }
+
+        Some(&mut node.value)
+    }
+
+    /// Reverses the list in place in a single pass,
+    /// relinking each node to its predecessor without recursion.
+    pub fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut rest = self.head.take();
+
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
         }
+
+        self.head = reversed;
+    }
+
+    /// Gets the n-th value counting from the tail,
+    /// where `nth_from_end(0)` is the last value.
+    /// Returns `None` if `n` is out of range.
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        // Move the leading node n nodes ahead
+        let mut lead = self.head.as_deref()?;
+        for _ in 0..n {
+            lead = lead.next.as_deref()?;
+        }
+
+        // Move both nodes until the leading one reaches the tail
+        let mut trail = self.head.as_deref()?;
+        while let Some(next) = lead.next.as_deref() {
+            lead = next;
+            trail = trail.next.as_deref()?;
+        }
+
+        Some(&trail.value)
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drops the nodes one by one,
+/// since dropping the boxed chain directly would recurse once per node
+/// and overflow the stack on long lists.
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+        }
+    }
+}
+
+/// Collects the values into a list in the iteration order in O(n) time.
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
     }
 }
 
@@ -24,8 +174,194 @@ mod tests {
 
     #[test]
     fn test_linked_list() {
-        let mut list = LinkedList::<i32>::new();
+        let list = LinkedList::<i32>::new();
 
         println!("list: {:?}", list);
     }
+
+    fn build_list(values: Vec<i32>) -> LinkedList<i32> {
+        LinkedList::from_vec(values)
+    }
+
+    /// Reads the values of the list from the head to the tail.
+    fn to_vec(list: &LinkedList<i32>) -> Vec<i32> {
+        let mut values = Vec::new();
+        let mut node = list.head.as_deref();
+        while let Some(current) = node {
+            values.push(current.value);
+            node = current.next.as_deref();
+        }
+
+        values
+    }
+
+    #[test]
+    fn test_push() {
+        let mut list = LinkedList::new();
+        assert!(list.head.is_none());
+
+        list.push_back(2);
+        assert_eq!(to_vec(&list), vec![2]);
+
+        list.push_front(1);
+        list.push_back(3);
+        list.push_front(0);
+        list.push_back(4);
+        assert_eq!(to_vec(&list), vec![0, 1, 2, 3, 4]);
+
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        assert_eq!(to_vec(&list), vec![1]);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        // Pushing to the back and popping from the front is FIFO
+        let mut list = build_list(vec![1, 2, 3]);
+        assert_eq!(list.pop_front(), Some(1));
+        list.push_back(4);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(to_vec(&list), vec![3, 4]);
+
+        // Pushing to and popping from the back is LIFO
+        list.push_back(5);
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.head.is_none());
+
+        // The emptied list is still usable
+        list.push_front(6);
+        assert_eq!(to_vec(&list), vec![6]);
+        assert_eq!(list.pop_front(), Some(6));
+        assert_eq!(list.pop_front(), None);
+        list.push_back(7);
+        list.push_front(8);
+        assert_eq!(list.pop_back(), Some(7));
+        assert_eq!(list.pop_back(), Some(8));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.push_front(0);
+        list.push_back(2);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+
+        list.pop_front();
+        assert_eq!(list.len(), 2);
+        list.pop_back();
+        list.pop_back();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        // Popping an empty list keeps the length at zero
+        list.pop_back();
+        list.pop_front();
+        assert_eq!(list.len(), 0);
+        assert_eq!(build_list((0..100).collect()).len(), 100);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = build_list(vec![1, 2, 3]);
+        list.reverse();
+        assert_eq!(to_vec(&list), vec![3, 2, 1]);
+        assert_eq!(list.len(), 3);
+
+        let mut list = LinkedList::<i32>::new();
+        list.reverse();
+        assert!(list.is_empty());
+
+        let mut list = build_list(vec![1]);
+        list.reverse();
+        assert_eq!(to_vec(&list), vec![1]);
+
+        // Reversing twice gives back the original
+        let values: Vec<i32> = (0..1000).collect();
+        let mut list = build_list(values.clone());
+        list.reverse();
+        list.reverse();
+        assert_eq!(to_vec(&list), values);
+
+        // The reversed list is still usable
+        let mut list = build_list(vec![1, 2]);
+        list.reverse();
+        list.push_back(0);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(to_vec(&list), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: LinkedList<i32> = (1..=5).collect();
+        assert_eq!(to_vec(&list), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+
+        let list = LinkedList::from_vec(vec![3, 1, 2]);
+        assert_eq!(to_vec(&list), vec![3, 1, 2]);
+        assert_eq!(list.len(), 3);
+
+        let mut list: LinkedList<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+        list.push_back(1);
+        assert_eq!(to_vec(&list), vec![1]);
+    }
+
+    #[test]
+    fn drop_long_list() {
+        let list: LinkedList<u32> = (0..1_000_000).collect();
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+
+        let mut list = LinkedList::new();
+        for value in 0..100_000 {
+            list.push_front(value);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = build_list(vec![1, 2, 3]);
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.get(100), None);
+
+        *list.get_mut(0).unwrap() = 10;
+        *list.get_mut(2).unwrap() += 20;
+        assert_eq!(list.get_mut(3), None);
+        assert_eq!(to_vec(&list), vec![10, 2, 23]);
+
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get_mut(0), None);
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        let list = build_list(vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.nth_from_end(0), Some(&5));
+        assert_eq!(list.nth_from_end(1), Some(&4));
+        assert_eq!(list.nth_from_end(2), Some(&3));
+        assert_eq!(list.nth_from_end(4), Some(&1));
+        assert_eq!(list.nth_from_end(5), None);
+        assert_eq!(list.nth_from_end(100), None);
+
+        let list = LinkedList::<i32>::new();
+        assert_eq!(list.nth_from_end(0), None);
+    }
 }
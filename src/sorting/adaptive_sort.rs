@@ -0,0 +1,114 @@
+use super::{ MergeSorter, SortOrder };
+
+/// Sorts the array by detecting the runs already in order and merging them,
+/// so that partially sorted data, such as a sorted log with a few records appended,
+/// is sorted in near-linear time.
+/// Strictly descending runs are reversed, which keeps the sort stable.
+pub fn adaptive_sort<T: PartialOrd + Clone>(array: &mut [T]) {
+    adaptive_sort_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts the array by detecting and merging the runs in the given order.
+pub fn adaptive_sort_ordered<T: PartialOrd + Clone>(array: &mut [T], order: SortOrder) {
+    // The end of each run
+    let mut run_ends = Vec::new();
+
+    let mut start = 0;
+    while start < array.len() {
+        let mut end = start + 1;
+        if end < array.len() && order.is_before(&array[end], &array[end - 1]) {
+            end += 1;
+            while end < array.len() && order.is_before(&array[end], &array[end - 1]) {
+                end += 1;
+            }
+            array[start..end].reverse();
+        } else {
+            // The first two elements are already known to be in order
+            end = (end + 1).min(array.len());
+            while end < array.len() && !order.is_before(&array[end], &array[end - 1]) {
+                end += 1;
+            }
+        }
+
+        run_ends.push(end);
+        start = end;
+    }
+
+    // Merge the neighboring runs pairwise until only one is left
+    let mut sorter = MergeSorter::new();
+    while run_ends.len() > 1 {
+        let mut merged_ends = Vec::with_capacity(run_ends.len().div_ceil(2));
+        let mut start = 0;
+        for pair in run_ends.chunks(2) {
+            if let [mid, end] = *pair {
+                sorter.merge_ordered(&mut array[start..end], mid - start, order);
+            }
+
+            let end = *pair.last().unwrap();
+            merged_ends.push(end);
+            start = end;
+        }
+
+        run_ends = merged_ends;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::count_comparisons;
+
+    #[test]
+    fn test_adaptive_sort() {
+        let mut array = [5, 2, 9, 1, 5, 6, 3, 8, 7, 4];
+        adaptive_sort(&mut array);
+        assert_eq!(array, [1, 2, 3, 4, 5, 5, 6, 7, 8, 9]);
+
+        let mut array: [i32; 0] = [];
+        adaptive_sort(&mut array);
+
+        let mut array = [1.5, -0.5];
+        adaptive_sort(&mut array);
+        assert_eq!(array, [-0.5, 1.5]);
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = [5, 2, 9, 1, 5, 6, 3, 8, 7, 4];
+        adaptive_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [9, 8, 7, 6, 5, 5, 4, 3, 2, 1]);
+
+        // Ascending runs are reversed as a whole for descending order
+        let data: Vec<i32> = (0..1000).collect();
+        let count = count_comparisons(&data, |array| adaptive_sort_ordered(array, SortOrder::Descending));
+        assert_eq!(count, data.len() - 1);
+    }
+
+    #[test]
+    fn count_adaptive_sort_on_sorted_input() {
+        let data: Vec<i32> = (0..1000).collect();
+        let count = count_comparisons(&data, |array| adaptive_sort(array));
+        assert_eq!(count, data.len() - 1);
+
+        let data: Vec<i32> = (0..1000).rev().collect();
+        let count = count_comparisons(&data, |array| adaptive_sort(array));
+        assert_eq!(count, data.len() - 1);
+    }
+
+    #[test]
+    fn count_adaptive_sort_on_appended_input() {
+        // A sorted log with a few records appended
+        let mut data: Vec<i32> = (0..1000).map(|i| 2 * i).collect();
+        data.extend([501, 3, 1999, 777, 41]);
+
+        let mut sorted = data.clone();
+        sorted.sort();
+        let mut array = data.clone();
+        adaptive_sort(&mut array);
+        assert_eq!(array, sorted);
+
+        // Far fewer than the n log n comparisons of a plain merge sort
+        let count = count_comparisons(&data, |array| adaptive_sort(array));
+        assert!(count < 3 * data.len(), "{} comparisons", count);
+    }
+}
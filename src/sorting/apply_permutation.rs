@@ -0,0 +1,64 @@
+/// Rearranges the data in place so that `data[k]` becomes the old `data[perm[k]]`,
+/// e.g., applying the permutation produced by [`argsort`](super::argsort) sorts the data.
+/// The cycles of the permutation are followed with swaps, so no element is cloned.
+pub fn apply_permutation<T>(data: &mut [T], perm: &[usize]) {
+    debug_assert!(is_permutation(perm, data.len()), "invalid permutation");
+
+    let mut visited = vec![false; data.len()];
+    for start in 0..data.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        // Pull each element of the cycle into place
+        let mut j = start;
+        loop {
+            let k = perm[j];
+            if k == start {
+                break;
+            }
+
+            data.swap(j, k);
+            visited[k] = true;
+            j = k;
+        }
+    }
+}
+
+/// Checks if `perm` is a permutation of `0..len`.
+fn is_permutation(perm: &[usize], len: usize) -> bool {
+    let mut seen = vec![false; len];
+    perm.len() == len &&
+        perm.iter().all(|&i| i < len && !std::mem::replace(&mut seen[i], true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::argsort;
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut data = vec!['a', 'b', 'c', 'd', 'e'];
+        apply_permutation(&mut data, &[3, 0, 4, 1, 2]);
+        assert_eq!(data, vec!['d', 'a', 'e', 'b', 'c']);
+
+        // Sort the keys and reorder the companion values together
+        let mut keys = vec![30, 10, 50, 20];
+        let mut values = vec!["thirty", "ten", "fifty", "twenty"];
+        let perm = argsort(&keys);
+        apply_permutation(&mut keys, &perm);
+        apply_permutation(&mut values, &perm);
+        assert_eq!(keys, vec![10, 20, 30, 50]);
+        assert_eq!(values, vec!["ten", "twenty", "thirty", "fifty"]);
+    }
+
+    #[test]
+    fn test_is_permutation() {
+        assert!(is_permutation(&[2, 0, 1], 3));
+        assert!(!is_permutation(&[0, 0, 1], 3));
+        assert!(!is_permutation(&[0, 3, 1], 3));
+        assert!(!is_permutation(&[0, 1], 3));
+    }
+}
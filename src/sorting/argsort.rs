@@ -0,0 +1,37 @@
+use std::cmp::Ordering;
+
+/// Gets the indices that would sort the slice in ascending order
+/// without moving the elements.
+/// The sort is stable, so equal elements keep the order of their indices.
+/// Incomparable elements, such as `NaN`, are treated as equal.
+pub fn argsort<T: PartialOrd>(slice: &[T]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..slice.len()).collect();
+    indices.sort_by(|&i, &j| slice[i].partial_cmp(&slice[j]).unwrap_or(Ordering::Equal));
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_argsort() {
+        let keys = [3, 1, 2];
+        let indices = argsort(&keys);
+        assert_eq!(indices, vec![1, 2, 0]);
+
+        // Sort a parallel array with the permutation
+        let names = ["c", "a", "b"];
+        let sorted: Vec<&str> = indices.iter().map(|&i| names[i]).collect();
+        assert_eq!(sorted, vec!["a", "b", "c"]);
+
+        let sorted: Vec<i32> = indices.iter().map(|&i| keys[i]).collect();
+        assert_eq!(sorted, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn argsort_stably() {
+        assert_eq!(argsort(&[2, 1, 2, 1]), vec![1, 3, 0, 2]);
+    }
+}
@@ -0,0 +1,58 @@
+/// Searches the sorted slice for the target.
+/// Returns `Ok` with the index of the first element equal to the target if there is one,
+/// or `Err` with the index where the target could be inserted to keep the slice sorted.
+pub fn binary_search<T: PartialOrd>(sorted: &[T], target: &T) -> Result<usize, usize> {
+    let mut low = 0;
+    let mut high = sorted.len();
+
+    // The first element not less than the target lies in [low, high]
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if &sorted[mid] < target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    match sorted.get(low) {
+        Some(value) if value == target => Ok(low),
+        _ => Err(low),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_search() {
+        let sorted = [1, 3, 5, 7];
+        assert_eq!(binary_search(&sorted, &1), Ok(0));
+        assert_eq!(binary_search(&sorted, &5), Ok(2));
+        assert_eq!(binary_search(&sorted, &7), Ok(3));
+        assert_eq!(binary_search(&sorted, &4), Err(2));
+
+        // Boundary targets
+        assert_eq!(binary_search(&sorted, &0), Err(0));
+        assert_eq!(binary_search(&sorted, &8), Err(4));
+
+        let sorted: [i32; 0] = [];
+        assert_eq!(binary_search(&sorted, &1), Err(0));
+
+        let sorted = [0.5, 1.5, 2.5];
+        assert_eq!(binary_search(&sorted, &1.5), Ok(1));
+        assert_eq!(binary_search(&sorted, &2.0), Err(2));
+    }
+
+    #[test]
+    fn search_among_duplicates() {
+        let sorted = [1, 2, 2, 2, 3];
+        assert_eq!(binary_search(&sorted, &2), Ok(1));
+        assert_eq!(binary_search(&sorted, &3), Ok(4));
+
+        let sorted = [4; 9];
+        assert_eq!(binary_search(&sorted, &4), Ok(0));
+        assert_eq!(binary_search(&sorted, &5), Err(9));
+    }
+}
@@ -0,0 +1,93 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+/// A wrapper that counts how many times it is compared.
+/// All wrappers sharing the same counter add to it.
+#[derive(Debug)]
+pub struct Counted<'a, T> {
+    pub value: &'a T,
+    pub counter: &'a Cell<usize>,
+}
+
+// Only the references are copied, so `T` need not be `Clone`
+impl<'a, T> Clone for Counted<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Counted<'a, T> {}
+
+impl<'a, T: PartialEq> PartialEq for Counted<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counter.set(self.counter.get() + 1);
+        self.value == other.value
+    }
+}
+
+impl<'a, T: PartialOrd> PartialOrd for Counted<'a, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.counter.set(self.counter.get() + 1);
+        self.value.partial_cmp(other.value)
+    }
+}
+
+/// Counts the number of comparisons the sort makes to sort the data.
+/// The data itself is left untouched.
+pub fn count_comparisons<T: PartialOrd>(data: &[T], sort: impl Fn(&mut [Counted<'_, T>])) -> usize {
+    let counter = Cell::new(0);
+
+    let mut array: Vec<Counted<T>> = data
+        .iter()
+        .map(|value| Counted {
+            value,
+            counter: &counter,
+        })
+        .collect();
+
+    sort(&mut array);
+
+    counter.get()
+}
+
+/// A linear congruential generator of reproducible pseudo-random test data.
+#[cfg(test)]
+pub(crate) struct TestRng(u64);
+
+#[cfg(test)]
+impl TestRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Generates the next pseudo-random number of 31 bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+
+    /// Generates a pseudo-random number in [0, modulus).
+    pub fn below(&mut self, modulus: u64) -> u64 {
+        self.next_u64() % modulus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::insertion_sort;
+
+    #[test]
+    fn count_insertion_sort_on_sorted_input() {
+        let data: Vec<i32> = (0..100).collect();
+        let count = count_comparisons(&data, |array| insertion_sort(array));
+        assert_eq!(count, data.len() - 1);
+    }
+
+    #[test]
+    fn count_insertion_sort_on_reversed_input() {
+        let data: Vec<i32> = (0..10).rev().collect();
+        let count = count_comparisons(&data, |array| insertion_sort(array));
+        assert_eq!(count, 10 * 9 / 2);
+    }
+}
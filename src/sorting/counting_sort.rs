@@ -0,0 +1,112 @@
+use super::SortOrder;
+
+/// Sorts the non-negative integers with counting sort in O(n + k) time and space,
+/// where k is the maximum value.
+/// It beats the comparison sorts when k is small relative to n,
+/// but the count table grows with k regardless of n.
+pub fn counting_sort(array: &mut [u32]) {
+    counting_sort_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts the non-negative integers with counting sort in the given order.
+pub fn counting_sort_ordered(array: &mut [u32], order: SortOrder) {
+    let Some(&max) = array.iter().max() else {
+        return;
+    };
+
+    match order {
+        SortOrder::Ascending => counting_sort_by_key(array, max as usize, |&value| value as usize),
+        SortOrder::Descending => counting_sort_by_key(array, max as usize, |&value| (max - value) as usize),
+    }
+}
+
+/// Sorts the array stably by the keys in `[0, max_key]` with counting sort
+/// in O(n + k) time and space, where k is the maximum key,
+/// so that it can serve as a digit pass of radix sort.
+///
+/// # Panics
+/// Panics if a key exceeds `max_key`.
+pub fn counting_sort_by_key<T: Clone>(array: &mut [T], max_key: usize, key: impl Fn(&T) -> usize) {
+    if array.is_empty() {
+        return;
+    }
+
+    let mut counts = vec![0; max_key + 1];
+    for value in array.iter() {
+        counts[key(value)] += 1;
+    }
+
+    // Turn the counts into the start position of each key
+    let mut start = 0;
+    for count in counts.iter_mut() {
+        let next = start + *count;
+        *count = start;
+        start = next;
+    }
+
+    // Place the elements in their input order to keep the sort stable
+    let mut sorted = array.to_vec();
+    for value in array.iter() {
+        let position = &mut counts[key(value)];
+        sorted[*position] = value.clone();
+        *position += 1;
+    }
+
+    array.clone_from_slice(&sorted);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::{ TestRng, insertion_sort };
+
+    #[test]
+    fn test_counting_sort() {
+        let mut array: [u32; 0] = [];
+        counting_sort(&mut array);
+
+        let mut array = [7; 10];
+        counting_sort(&mut array);
+        assert_eq!(array, [7; 10]);
+
+        let mut array = [3, 0, 2, 0, 5, 1, 3];
+        counting_sort(&mut array);
+        assert_eq!(array, [0, 0, 1, 2, 3, 3, 5]);
+
+        // Compare against insertion sort on random arrays
+        let mut rng = TestRng::new(3);
+        for len in [1, 2, 10, 100, 1000] {
+            let mut array: Vec<u32> = (0..len).map(|_| rng.below(50) as u32).collect();
+            let mut expected = array.clone();
+            insertion_sort(&mut expected);
+
+            counting_sort(&mut array);
+            assert_eq!(array, expected);
+        }
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = [3, 0, 2, 0, 5, 1, 3];
+        counting_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [5, 3, 3, 2, 1, 0, 0]);
+
+        let mut array: [u32; 0] = [];
+        counting_sort_ordered(&mut array, SortOrder::Descending);
+    }
+
+    #[test]
+    fn sort_by_key_stably() {
+        let mut pairs = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+        counting_sort_by_key(&mut pairs, 2, |pair| pair.0);
+
+        let labels: String = pairs.iter().map(|pair| pair.1).collect();
+        assert_eq!(labels, "ebdac");
+
+        // Two stable digit passes sort by both digits
+        let mut array = [21, 13, 32, 11, 23, 12];
+        counting_sort_by_key(&mut array, 9, |value| value % 10);
+        counting_sort_by_key(&mut array, 9, |value| value / 10);
+        assert_eq!(array, [11, 12, 13, 21, 23, 32]);
+    }
+}
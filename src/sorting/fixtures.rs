@@ -0,0 +1,25 @@
+/// A person compared by age only,
+/// so that people of equal age show whether a sort is stable.
+#[derive(Debug, Clone)]
+pub(crate) struct Person {
+    pub name: String,
+    pub age: u32,
+}
+
+impl Person {
+    pub fn new(name: &str, age: u32) -> Self {
+        Self { name: name.to_string(), age }
+    }
+}
+
+impl PartialEq for Person {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.age == other.age
+    }
+}
+
+impl PartialOrd for Person {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.age.partial_cmp(&other.age)
+    }
+}
@@ -0,0 +1,137 @@
+use super::SortOrder;
+
+/// Sorts the array in place with heapsort,
+/// which takes O(n log n) time in the worst case without recursion or extra memory.
+/// The sort is not stable.
+pub fn heap_sort<T: PartialOrd>(array: &mut [T]) {
+    heap_sort_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts the array in place with heapsort in the given order.
+/// The sort is not stable.
+pub fn heap_sort_ordered<T: PartialOrd>(array: &mut [T], order: SortOrder) {
+    // Build a heap bottom-up, starting from the last parent,
+    // whose root belongs at the end of the order
+    for root in (0..array.len() / 2).rev() {
+        sift_down(array, root, order);
+    }
+
+    // Move the root to the end and restore the heap on the rest
+    for end in (1..array.len()).rev() {
+        array.swap(0, end);
+        sift_down(&mut array[..end], 0, order);
+    }
+}
+
+/// Moves the element at the root down the heap until no child belongs after it in the order,
+/// assuming both subtrees of the root are already heaps.
+/// In ascending order, the heap is a max-heap.
+fn sift_down<T: PartialOrd>(heap: &mut [T], root: usize, order: SortOrder) {
+    let mut parent = root;
+
+    loop {
+        let left = 2 * parent + 1;
+        if left >= heap.len() {
+            break;
+        }
+
+        // Choose the child belonging later in the order
+        let right = left + 1;
+        let child = if right < heap.len() && order.is_before(&heap[left], &heap[right]) {
+            right
+        } else {
+            left
+        };
+
+        if !order.is_before(&heap[parent], &heap[child]) {
+            break;
+        }
+
+        heap.swap(parent, child);
+        parent = child;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::fixtures::Person;
+
+    /// Checks if the array is a max-heap.
+    fn is_max_heap<T: PartialOrd>(heap: &[T]) -> bool {
+        (1..heap.len()).all(|child| heap[(child - 1) / 2] >= heap[child])
+    }
+
+    #[test]
+    fn test_sift_down() {
+        // Both subtrees of the root are max-heaps
+        let mut heap = [1, 9, 8, 5, 6, 7, 3];
+        sift_down(&mut heap, 0, SortOrder::Ascending);
+        assert_eq!(heap, [9, 6, 8, 5, 1, 7, 3]);
+        assert!(is_max_heap(&heap));
+
+        // A leaf stays in place
+        let mut heap = [1, 2, 3];
+        sift_down(&mut heap, 2, SortOrder::Ascending);
+        assert_eq!(heap, [1, 2, 3]);
+
+        // Sifting a subtree leaves the rest untouched
+        let mut heap = [0, 1, 9, 4, 5];
+        sift_down(&mut heap, 1, SortOrder::Ascending);
+        assert_eq!(heap, [0, 5, 9, 4, 1]);
+
+        let mut heap: [i32; 0] = [];
+        sift_down(&mut heap, 0, SortOrder::Ascending);
+    }
+
+    #[test]
+    fn sort_numbers() {
+        let mut array: [i32; 0] = [];
+        heap_sort(&mut array);
+
+        let mut array = [1];
+        heap_sort(&mut array);
+        assert_eq!(array, [1]);
+
+        let mut array = [5, -1, 3, 3, 0, 8, -1, 2];
+        heap_sort(&mut array);
+        assert_eq!(array, [-1, -1, 0, 2, 3, 3, 5, 8]);
+
+        let mut array: Vec<i32> = (0..1000).rev().collect();
+        heap_sort(&mut array);
+        assert_eq!(array, (0..1000).collect::<Vec<i32>>());
+
+        let mut array = vec![4; 100];
+        heap_sort(&mut array);
+        assert_eq!(array, vec![4; 100]);
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = [5, -1, 3, 3, 0, 8, -1, 2];
+        heap_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [8, 5, 3, 3, 2, 0, -1, -1]);
+
+        // A min-heap in descending order
+        let mut heap = [9, 1, 2, 5, 6, 7, 3];
+        sift_down(&mut heap, 0, SortOrder::Descending);
+        assert_eq!(heap, [1, 5, 2, 9, 6, 7, 3]);
+    }
+
+    #[test]
+    fn sort_people() {
+        let mut people = [
+            Person::new("Isaac", 24),
+            Person::new("Jane", 18),
+            Person::new("John", 30),
+            Person::new("Alice", 24),
+            Person::new("Bob", 18),
+        ];
+
+        heap_sort(&mut people);
+
+        let ages: Vec<u32> = people.iter().map(|person| person.age).collect();
+        assert_eq!(ages, [18, 18, 24, 24, 30]);
+        assert_eq!(people[4].name, "John");
+    }
+}
@@ -0,0 +1,43 @@
+/// Finds the index where the value should be inserted to keep the slice sorted.
+/// If there are elements equal to the value,
+/// the index after the last of them is returned so that the insertion is stable.
+pub fn insertion_index<T: PartialOrd>(sorted: &[T], value: &T) -> usize {
+    let mut low = 0;
+    let mut high = sorted.len();
+
+    // The index lies in [low, high]
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if value < &sorted[mid] {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_index() {
+        let sorted = [1, 3, 5, 7];
+        assert_eq!(insertion_index(&sorted, &0), 0);
+        assert_eq!(insertion_index(&sorted, &4), 2);
+        assert_eq!(insertion_index(&sorted, &8), 4);
+
+        let sorted: [i32; 0] = [];
+        assert_eq!(insertion_index(&sorted, &1), 0);
+    }
+
+    #[test]
+    fn insert_among_duplicates() {
+        let sorted = [1, 2, 2, 2, 3];
+        assert_eq!(insertion_index(&sorted, &2), 4);
+        assert_eq!(insertion_index(&sorted, &1), 1);
+        assert_eq!(insertion_index(&sorted, &3), 5);
+    }
+}
@@ -1,22 +1,21 @@
+use std::cmp::Ordering;
+use super::SortOrder;
+
 pub fn insertion_sort<T: PartialOrd>(ordered_array: &mut [T]) {
+    insertion_sort_ordered(ordered_array, SortOrder::Ascending);
+}
+
+/// Sorts the array stably with insertion sort by the comparator.
+pub fn insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(ordered_array: &mut [T], mut compare: F) {
     for j in 1..ordered_array.len() {
         let value = &ordered_array[j];
 
-        let mut i = j - 1;
-
-        // k is the position to insert
-        let k = loop {
-            let other_value = &ordered_array[i];
-            if value >= other_value {
-                break i + 1;
-            }
-
-            if i == 0 {
-                break 0;
-            }
-
-            i -= 1;
-        };
+        // k is the position to insert, i.e.,
+        // the value is less than every value in ordered_array[k..j]
+        let mut k = j;
+        while k > 0 && compare(value, &ordered_array[k - 1]) == Ordering::Less {
+            k -= 1;
+        }
 
         if k == j {
             continue;
@@ -27,6 +26,22 @@ pub fn insertion_sort<T: PartialOrd>(ordered_array: &mut [T]) {
     }
 }
 
+/// Sorts the array stably with insertion sort in the given order.
+/// In either order, an incomparable value is moved before the values it cannot be compared with.
+pub fn insertion_sort_ordered<T: PartialOrd>(ordered_array: &mut [T], order: SortOrder) {
+    insertion_sort_by(ordered_array, |a, b| match (a.partial_cmp(b), order) {
+        (Some(ordering), SortOrder::Ascending) => ordering,
+        (Some(ordering), SortOrder::Descending) => ordering.reverse(),
+        (None, _) => Ordering::Less,
+    });
+}
+
+/// Sorts the array stably with insertion sort by the key of each element.
+/// The key is computed on every comparison.
+pub fn insertion_sort_by_key<T, K: PartialOrd, F: FnMut(&T) -> K>(ordered_array: &mut [T], mut key: F) {
+    insertion_sort_by(ordered_array, |a, b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Less));
+}
+
 unsafe fn _insertion_sort_unsafe<T: PartialOrd>(ordered_array: &mut [T]) {
     for j in 1..ordered_array.len() {
         let key = unsafe { std::ptr::read(&ordered_array[j]) };
@@ -65,24 +80,7 @@ unsafe fn _insertion_sort_unsafe<T: PartialOrd>(ordered_array: &mut [T]) {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[derive(Debug)]
-    struct Person {
-        name: String,
-        age: u32,
-    }
-
-    impl PartialEq for Person {
-        fn eq(&self, other: &Self) -> bool {
-            self.name == other.name && self.age == other.age
-        }
-    }
-
-    impl PartialOrd for Person {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            self.age.partial_cmp(&other.age)
-        }
-    }
+    use crate::sorting::fixtures::Person;
 
     #[test]
     fn test_rotate() {
@@ -120,6 +118,117 @@ mod tests {
         println!("{:?}", people);
     }
 
+    #[test]
+    fn sort_new_minimum_to_front() {
+        let mut array = [3, 2, 1, 0];
+        insertion_sort(&mut array);
+        assert_eq!(array, [0, 1, 2, 3]);
+
+        let mut array = [1, 2, 3, 0];
+        insertion_sort(&mut array);
+        assert_eq!(array, [0, 1, 2, 3]);
+
+        let mut array = [0, 0];
+        insertion_sort(&mut array);
+        assert_eq!(array, [0, 0]);
+
+        let mut array = [1, 0];
+        insertion_sort(&mut array);
+        assert_eq!(array, [0, 1]);
+
+        let mut array: [i32; 0] = [];
+        insertion_sort(&mut array);
+
+        let mut array = [5];
+        insertion_sort(&mut array);
+        assert_eq!(array, [5]);
+    }
+
+    #[test]
+    fn sort_incomparable_values() {
+        // NaN moves before the values it cannot be compared with
+        let mut array = [1.0, f64::NAN, 0.0];
+        insertion_sort(&mut array);
+        assert_eq!(array.map(f64::to_bits), [0.0, f64::NAN, 1.0].map(f64::to_bits));
+
+        let mut ordered = [1.0, f64::NAN, 0.0];
+        insertion_sort_ordered(&mut ordered, SortOrder::Ascending);
+        assert_eq!(ordered.map(f64::to_bits), array.map(f64::to_bits));
+
+        let mut ordered = [0.0, f64::NAN, 1.0];
+        insertion_sort_ordered(&mut ordered, SortOrder::Descending);
+        assert_eq!(ordered.map(f64::to_bits), [1.0, f64::NAN, 0.0].map(f64::to_bits));
+    }
+
+    #[test]
+    fn sort_all_equal_stably() {
+        let mut pairs = [(1, 'a'), (1, 'b'), (1, 'c'), (1, 'd')];
+        insertion_sort_by_key(&mut pairs, |pair| pair.0);
+        assert_eq!(pairs, [(1, 'a'), (1, 'b'), (1, 'c'), (1, 'd')]);
+
+        // The new minimum moves ahead of all equal values
+        let mut pairs = [(1, 'a'), (1, 'b'), (1, 'c'), (0, 'd')];
+        insertion_sort_by_key(&mut pairs, |pair| pair.0);
+        assert_eq!(pairs, [(0, 'd'), (1, 'a'), (1, 'b'), (1, 'c')]);
+    }
+
+    #[test]
+    fn sort_people_by_closure() {
+        let mut people = [
+            Person::new("Isaac", 24),
+            Person::new("Jane", 18),
+            Person::new("John", 30),
+            Person::new("Alice", 24),
+            Person::new("Bob", 18),
+        ];
+
+        // By name descending
+        insertion_sort_by(&mut people, |a, b| b.name.cmp(&a.name));
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["John", "Jane", "Isaac", "Bob", "Alice"]);
+
+        // By age, where people of equal age keep the order above
+        insertion_sort_by_key(&mut people, |person| person.age);
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["Jane", "Bob", "Isaac", "Alice", "John"]);
+
+        // By age descending
+        insertion_sort_by(&mut people, |a, b| b.age.cmp(&a.age));
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["John", "Isaac", "Alice", "Jane", "Bob"]);
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = [3, -1, 4, 1, 5, 9, 2, 6];
+        insertion_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [9, 6, 5, 4, 3, 2, 1, -1]);
+
+        let mut people = [
+            Person::new("Isaac", 24),
+            Person::new("Jane", 18),
+            Person::new("John", 30),
+            Person::new("Alice", 24),
+            Person::new("Bob", 18),
+        ];
+
+        // People of equal age keep the input order
+        insertion_sort_ordered(&mut people, SortOrder::Descending);
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["John", "Isaac", "Alice", "Jane", "Bob"]);
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let mut array = [-3, 1, -2, 0, 2, -1];
+        insertion_sort_by_key(&mut array, |value: &i32| value.abs());
+        assert_eq!(array, [0, 1, -1, -2, 2, -3]);
+
+        let mut array = [3, 1, 2];
+        insertion_sort(&mut array);
+        assert_eq!(array, [1, 2, 3]);
+    }
+
     #[test]
     fn double_drop() {
         let s = "Hello, world!".to_string();
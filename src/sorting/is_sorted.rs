@@ -0,0 +1,45 @@
+use std::cmp::Ordering;
+
+/// Checks if the array is in non-decreasing order.
+/// Two neighbors that cannot be compared, e.g., when either is `f64::NAN`,
+/// make the array unsorted.
+pub fn is_sorted<T: PartialOrd>(array: &[T]) -> bool {
+    array.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/// Checks if the array is in non-decreasing order by the comparator,
+/// i.e., no element compares greater than the one after it.
+pub fn is_sorted_by<T, F: FnMut(&T, &T) -> Ordering>(array: &[T], mut compare: F) -> bool {
+    array.windows(2).all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(is_sorted::<i32>(&[]));
+        assert!(is_sorted(&[1]));
+        assert!(is_sorted(&[-1, 0, 0, 2, 5]));
+        assert!(is_sorted(&[3, 3, 3, 3]));
+        assert!(!is_sorted(&[1, 0]));
+        assert!(!is_sorted(&[0, 1, 2, 4, 3]));
+
+        assert!(is_sorted(&[-1.5, 0.0, f64::INFINITY]));
+        assert!(!is_sorted(&[0.0, f64::NAN, 1.0]));
+        assert!(is_sorted(&[f64::NAN]));
+        assert!(!is_sorted(&[f64::NAN, f64::NAN]));
+    }
+
+    #[test]
+    fn test_is_sorted_by() {
+        assert!(is_sorted_by(&[5, 3, 3, 1], |a: &i32, b: &i32| b.cmp(a)));
+        assert!(!is_sorted_by(&[1, 3], |a: &i32, b: &i32| b.cmp(a)));
+        assert!(is_sorted_by(&[0, -1, 2, -3], |a: &i32, b: &i32| a.abs().cmp(&b.abs())));
+        assert!(is_sorted_by::<i32, _>(&[], |a, b| a.cmp(b)));
+
+        // A total order on floats places NaN last
+        assert!(is_sorted_by(&[0.0, 1.0, f64::NAN], f64::total_cmp));
+    }
+}
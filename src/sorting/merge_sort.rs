@@ -0,0 +1,67 @@
+use super::{ MergeSorter, SortOrder };
+
+/// Sorts the array with a stable top-down merge sort in O(n log n) time,
+/// using an auxiliary buffer of at most half the length of the array.
+/// Use [`MergeSorter`] instead to reuse the buffer across many calls.
+pub fn merge_sort<T: PartialOrd + Clone>(array: &mut [T]) {
+    MergeSorter::new().sort(array);
+}
+
+/// Sorts the array with a stable merge sort in the given order.
+pub fn merge_sort_ordered<T: PartialOrd + Clone>(array: &mut [T], order: SortOrder) {
+    MergeSorter::new().sort_ordered(array, order);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::fixtures::Person;
+
+    #[test]
+    fn test_merge_sort() {
+        let mut array: [i32; 0] = [];
+        merge_sort(&mut array);
+        assert!(array.is_empty());
+
+        let mut array = [42];
+        merge_sort(&mut array);
+        assert_eq!(array, [42]);
+
+        let mut array = [1, 2, 3, 4, 5, 6, 7];
+        merge_sort(&mut array);
+        assert_eq!(array, [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut array = [7, 6, 5, 4, 3, 2, 1];
+        merge_sort(&mut array);
+        assert_eq!(array, [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut array = [3.5, -1.0, 2.0, 0.0, 2.0, -7.25];
+        merge_sort(&mut array);
+        assert_eq!(array, [-7.25, -1.0, 0.0, 2.0, 2.0, 3.5]);
+
+        merge_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [3.5, 2.0, 2.0, 0.0, -1.0, -7.25]);
+    }
+
+    #[test]
+    fn sort_people_stably() {
+        let mut people = [
+            Person::new("Isaac", 24),
+            Person::new("Jane", 18),
+            Person::new("John", 30),
+            Person::new("Alice", 24),
+            Person::new("Bob", 18),
+            Person::new("Carol", 24),
+        ];
+
+        merge_sort(&mut people);
+
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["Jane", "Bob", "Isaac", "Alice", "Carol", "John"]);
+
+        // People of equal age keep the input order in descending order as well
+        merge_sort_ordered(&mut people, SortOrder::Descending);
+        let names: Vec<&str> = people.iter().map(|person| person.name.as_str()).collect();
+        assert_eq!(names, ["John", "Isaac", "Alice", "Carol", "Jane", "Bob"]);
+    }
+}
@@ -0,0 +1,120 @@
+use super::SortOrder;
+
+/// A stable merge sorter that reuses its scratch buffer across calls,
+/// so that sorting many small slices does not allocate each time.
+/// The buffer only grows when a slice is longer than any seen before.
+#[derive(Debug, Clone)]
+pub struct MergeSorter<T> {
+    scratch: Vec<T>,
+}
+
+impl<T: PartialOrd + Clone> MergeSorter<T> {
+    /// Creates a sorter with an empty scratch buffer.
+    pub fn new() -> Self {
+        Self { scratch: Vec::new() }
+    }
+
+    /// Sorts the slice with merge sort.
+    pub fn sort(&mut self, slice: &mut [T]) {
+        self.sort_ordered(slice, SortOrder::Ascending);
+    }
+
+    /// Sorts the slice with merge sort in the given order.
+    pub fn sort_ordered(&mut self, slice: &mut [T], order: SortOrder) {
+        if slice.len() < 2 {
+            return;
+        }
+
+        let mid = slice.len() / 2;
+        self.sort_ordered(&mut slice[..mid], order);
+        self.sort_ordered(&mut slice[mid..], order);
+        self.merge_ordered(slice, mid, order);
+    }
+
+    /// Merges the halves `slice[..mid]` and `slice[mid..]` sorted in the given order.
+    pub(crate) fn merge_ordered(&mut self, slice: &mut [T], mid: usize, order: SortOrder) {
+        // Skip merging if the halves are already in order
+        if !order.is_before(&slice[mid], &slice[mid - 1]) {
+            return;
+        }
+
+        // Clearing keeps the capacity, so the buffer is reused
+        self.scratch.clear();
+        self.scratch.extend_from_slice(&slice[..mid]);
+
+        // Take from the left half on ties to keep the sort stable
+        let (mut i, mut j, mut k) = (0, mid, 0);
+        while i < self.scratch.len() && j < slice.len() {
+            if order.is_before(&slice[j], &self.scratch[i]) {
+                slice[k] = slice[j].clone();
+                j += 1;
+            } else {
+                slice[k] = self.scratch[i].clone();
+                i += 1;
+            }
+            k += 1;
+        }
+
+        // The rest of the right half is already in place
+        for value in self.scratch.drain(i..) {
+            slice[k] = value;
+            k += 1;
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Default for MergeSorter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_sorter() {
+        let mut sorter = MergeSorter::new();
+
+        let mut array = [5, 2, 9, 1, 5, 6, 3, 8, 7, 4];
+        sorter.sort(&mut array);
+        assert_eq!(array, [1, 2, 3, 4, 5, 5, 6, 7, 8, 9]);
+
+        let capacity = sorter.scratch.capacity();
+        let buffer = sorter.scratch.as_ptr();
+
+        // Sort many short slices through the same sorter
+        for seed in 0..1000_i32 {
+            let mut array: Vec<i32> = (0..8).map(|i| (seed * 31 + i * 17) % 13).collect();
+            let mut expected = array.clone();
+            expected.sort();
+
+            sorter.sort(&mut array);
+            assert_eq!(array, expected);
+        }
+
+        // The buffer is not reallocated
+        assert_eq!(sorter.scratch.capacity(), capacity);
+        assert_eq!(sorter.scratch.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn sort_stably() {
+        // Compare by the first component only
+        #[derive(Debug, Clone, PartialEq)]
+        struct Pair(i32, char);
+
+        impl PartialOrd for Pair {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        let mut pairs = [Pair(2, 'a'), Pair(1, 'b'), Pair(2, 'c'), Pair(1, 'd'), Pair(0, 'e')];
+        MergeSorter::new().sort(&mut pairs);
+
+        let labels: String = pairs.iter().map(|pair| pair.1).collect();
+        assert_eq!(labels, "ebdac");
+    }
+}
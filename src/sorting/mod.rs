@@ -1,3 +1,85 @@
+mod sort_order;
+
+pub use sort_order::SortOrder;
+
+mod is_sorted;
+
+pub use is_sorted::{ is_sorted, is_sorted_by };
+
 mod insertion_sort;
 
-pub use insertion_sort::insertion_sort;
+pub use insertion_sort::{
+    insertion_sort,
+    insertion_sort_by,
+    insertion_sort_by_key,
+    insertion_sort_ordered,
+};
+
+mod merge_sort;
+
+pub use merge_sort::{ merge_sort, merge_sort_ordered };
+
+mod quick_sort;
+
+pub use quick_sort::{ quick_sort, quick_sort_ordered };
+
+mod heap_sort;
+
+pub use heap_sort::{ heap_sort, heap_sort_ordered };
+
+mod selection_sort;
+
+pub use selection_sort::selection_sort;
+
+mod quick_sort_3way;
+
+pub use quick_sort_3way::{ quick_sort_3way, quick_sort_3way_ordered };
+
+mod insertion_index;
+
+pub use insertion_index::insertion_index;
+
+mod binary_search;
+
+pub use binary_search::binary_search;
+
+mod counting_sort;
+
+pub use counting_sort::{ counting_sort, counting_sort_by_key, counting_sort_ordered };
+
+mod counted;
+
+pub use counted::{ Counted, count_comparisons };
+#[cfg(test)]
+pub(crate) use counted::TestRng;
+
+mod stable_partition;
+
+pub use stable_partition::stable_partition;
+
+mod merge_sorter;
+
+pub use merge_sorter::MergeSorter;
+
+mod rank;
+
+pub use rank::rank;
+
+mod argsort;
+
+pub use argsort::argsort;
+
+mod apply_permutation;
+
+pub use apply_permutation::apply_permutation;
+
+mod adaptive_sort;
+
+pub use adaptive_sort::{ adaptive_sort, adaptive_sort_ordered };
+
+mod sort_network;
+
+pub use sort_network::{ sort_network, sort_network_ordered };
+
+#[cfg(test)]
+mod fixtures;
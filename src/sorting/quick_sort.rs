@@ -0,0 +1,164 @@
+use super::{ SortOrder, insertion_sort_ordered };
+
+/// Slices shorter than this are sorted with insertion sort.
+const INSERTION_SORT_CUTOFF: usize = 16;
+
+/// Sorts the array in place with quicksort using a Hoare-style partition.
+/// The pivot is the median of the first, middle and last elements,
+/// so that sorted and reverse-sorted inputs take O(n log n) time,
+/// and elements equal to the pivot are split evenly between both sides,
+/// so that inputs with many equal elements do as well.
+/// Short slices are sorted with [`insertion_sort`] instead.
+pub fn quick_sort<T: PartialOrd>(array: &mut [T]) {
+    quick_sort_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts the array in place with quicksort in the given order.
+/// See [`quick_sort`] for the details.
+pub fn quick_sort_ordered<T: PartialOrd>(array: &mut [T], order: SortOrder) {
+    let mut array = array;
+
+    while array.len() >= INSERTION_SORT_CUTOFF {
+        let pivot = partition(array, order);
+
+        // Recurse into the smaller part and loop on the larger one
+        // so that the recursion depth is O(log n)
+        let (less, rest) = array.split_at_mut(pivot);
+        let greater = &mut rest[1..];
+        if less.len() < greater.len() {
+            quick_sort_ordered(less, order);
+            array = greater;
+        } else {
+            quick_sort_ordered(greater, order);
+            array = less;
+        }
+    }
+
+    insertion_sort_ordered(array, order);
+}
+
+/// Sorts the first, middle and last elements among themselves,
+/// so that the median of the three is in the middle.
+fn sort_three<T: PartialOrd>(array: &mut [T], order: SortOrder) {
+    let (a, b, c) = (0, array.len() / 2, array.len() - 1);
+
+    if order.is_before(&array[b], &array[a]) {
+        array.swap(a, b);
+    }
+    if order.is_before(&array[c], &array[b]) {
+        array.swap(b, c);
+        if order.is_before(&array[b], &array[a]) {
+            array.swap(a, b);
+        }
+    }
+}
+
+/// Partitions the array around the median-of-three pivot.
+/// Returns the final index of the pivot,
+/// where no element before it belongs after it, and no element after it belongs before it.
+/// The array must have at least two elements.
+fn partition<T: PartialOrd>(array: &mut [T], order: SortOrder) -> usize {
+    // Move the median of three to the front as the pivot
+    sort_three(array, order);
+    array.swap(0, array.len() / 2);
+
+    let last = array.len() - 1;
+    let mut i = 0;
+    let mut j = array.len();
+    loop {
+        // Both scans stop at elements equal to the pivot
+        i += 1;
+        while i < last && order.is_before(&array[i], &array[0]) {
+            i += 1;
+        }
+
+        // The scan stops at the pivot itself at the latest
+        j -= 1;
+        while order.is_before(&array[0], &array[j]) {
+            j -= 1;
+        }
+
+        if i >= j {
+            break;
+        }
+
+        array.swap(i, j);
+    }
+
+    // Move the pivot into its final position
+    array.swap(0, j);
+
+    j
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::{ TestRng, count_comparisons, is_sorted };
+
+    /// Generates pseudo-random integers in [0, modulus).
+    fn random_array(len: usize, modulus: u64, seed: u64) -> Vec<u64> {
+        let mut rng = TestRng::new(seed);
+        (0..len).map(|_| rng.below(modulus)).collect()
+    }
+
+    #[test]
+    fn sort_numbers() {
+        let mut array: [i32; 0] = [];
+        quick_sort(&mut array);
+
+        let mut array = [1];
+        quick_sort(&mut array);
+        assert_eq!(array, [1]);
+
+        let mut array = [5, -1, 3, 3, 0, 8, -1, 2];
+        quick_sort(&mut array);
+        assert_eq!(array, [-1, -1, 0, 2, 3, 3, 5, 8]);
+
+        let mut array = [2.5, -0.5, 10.0, 3.0, 1.0, 0.0, 7.5, -3.0, 4.0, 4.0, 9.0, 6.0, 5.5, 8.0, 2.0, 1.5, -1.0];
+        quick_sort(&mut array);
+        assert!(is_sorted(&array));
+
+        for (len, modulus) in [(17, 5), (100, 1000), (1000, 10), (10_000, 1 << 31)] {
+            let mut array = random_array(len, modulus, len as u64);
+            let mut expected = array.clone();
+            expected.sort();
+
+            quick_sort(&mut array);
+            assert_eq!(array, expected);
+        }
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = random_array(1000, 100, 5);
+        let mut expected = array.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        quick_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, expected);
+
+        let mut array = [3, 1, 2];
+        quick_sort_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_adversarial_inputs() {
+        let n = 4096;
+        let sorted: Vec<i32> = (0..n).collect();
+        let reversed: Vec<i32> = (0..n).rev().collect();
+        let equal = vec![7; n as usize];
+        let organ_pipe: Vec<i32> = (0..n / 2).chain((0..n / 2).rev()).collect();
+
+        for data in [sorted, reversed, equal, organ_pipe] {
+            let mut array = data.clone();
+            quick_sort(&mut array);
+            assert!(is_sorted(&array));
+
+            // A quadratic sort would make millions of comparisons
+            let comparisons = count_comparisons(&data, |array| quick_sort(array));
+            assert!(comparisons < 4 * (n as usize) * 12, "{} comparisons", comparisons);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::SortOrder;
+
+/// Sorts the array with quicksort using a three-way partition,
+/// which groups the elements equal to the pivot in the middle.
+/// It takes linear time if there are only a few distinct elements.
+pub fn quick_sort_3way<T: PartialOrd + Clone>(array: &mut [T]) {
+    quick_sort_3way_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts the array with quicksort using a three-way partition in the given order.
+pub fn quick_sort_3way_ordered<T: PartialOrd + Clone>(array: &mut [T], order: SortOrder) {
+    let mut array = array;
+
+    while array.len() > 1 {
+        let (less_end, greater_start) = partition_3way(array, order);
+
+        // Recurse into the smaller part and loop on the larger one
+        // so that the recursion depth is O(log n)
+        let (less, rest) = array.split_at_mut(less_end);
+        let greater = &mut rest[greater_start - less_end..];
+        if less.len() < greater.len() {
+            quick_sort_3way_ordered(less, order);
+            array = greater;
+        } else {
+            quick_sort_3way_ordered(greater, order);
+            array = less;
+        }
+    }
+}
+
+/// Partitions the array into three parts:
+/// elements before, equal to, and after the pivot in the order.
+/// Returns the end of the first part and the start of the last part.
+fn partition_3way<T: PartialOrd + Clone>(array: &mut [T], order: SortOrder) -> (usize, usize) {
+    // Choose the middle element as the pivot to avoid the worst case on sorted inputs
+    let pivot = array[array.len() / 2].clone();
+
+    // Invariant:
+    // array[..lt] is before the pivot, array[lt..i] is equal to it, and array[gt..] is after it
+    let mut lt = 0;
+    let mut i = 0;
+    let mut gt = array.len();
+
+    while i < gt {
+        if order.is_before(&array[i], &pivot) {
+            array.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if order.is_before(&pivot, &array[i]) {
+            gt -= 1;
+            array.swap(i, gt);
+        } else {
+            i += 1;
+        }
+    }
+
+    (lt, gt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting::TestRng;
+
+    #[test]
+    fn sort_numbers() {
+        let mut array = [5, -1, 3, 3, 0, 8, -1, 2];
+        quick_sort_3way(&mut array);
+        assert_eq!(array, [-1, -1, 0, 2, 3, 3, 5, 8]);
+
+        let mut array: [i32; 0] = [];
+        quick_sort_3way(&mut array);
+
+        let mut array: Vec<i32> = (0..1000).rev().collect();
+        quick_sort_3way(&mut array);
+        assert_eq!(array, (0..1000).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn sort_few_distinct_keys() {
+        // Pseudo-random keys drawn from only three values
+        let mut rng = TestRng::new(7);
+        let mut array: Vec<u8> = (0..100_000).map(|_| rng.below(3) as u8).collect();
+        let mut expected = array.clone();
+        expected.sort();
+
+        quick_sort_3way(&mut array);
+        assert_eq!(array, expected);
+
+        // All equal
+        let mut array = vec![1; 100_000];
+        quick_sort_3way(&mut array);
+        assert!(array.iter().all(|&value| value == 1));
+    }
+
+    #[test]
+    fn sort_descending() {
+        let mut array = [5, -1, 3, 3, 0, 8, -1, 2];
+        quick_sort_3way_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [8, 5, 3, 3, 2, 0, -1, -1]);
+
+        let mut array: Vec<i32> = (0..1000).collect();
+        quick_sort_3way_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, (0..1000).rev().collect::<Vec<i32>>());
+    }
+}
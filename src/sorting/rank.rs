@@ -0,0 +1,38 @@
+use super::argsort;
+
+/// Gets the 0-based rank of each element if the slice were sorted.
+/// Equal elements share the smallest rank among them,
+/// e.g., the ranks of `[10, 20, 20, 30]` are `[0, 1, 1, 3]`.
+/// Elements are ordered as in [`argsort`], but ties are decided by `==`,
+/// so an element not equal to itself, such as `NaN`, never shares a rank,
+/// e.g., the ranks of `[NaN, NaN]` are `[0, 1]`.
+pub fn rank<T: PartialOrd>(slice: &[T]) -> Vec<usize> {
+    let indices = argsort(slice);
+
+    let mut ranks = vec![0; slice.len()];
+    for (position, &index) in indices.iter().enumerate() {
+        // Share the rank of the previous element if they are equal
+        ranks[index] = match position.checked_sub(1).map(|previous| indices[previous]) {
+            Some(previous) if slice[previous] == slice[index] => ranks[previous],
+            _ => position,
+        };
+    }
+
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(rank(&[30, 10, 20]), vec![2, 0, 1]);
+        assert_eq!(rank(&[20, 10, 30, 20]), vec![1, 0, 3, 1]);
+        assert_eq!(rank::<i32>(&[]), Vec::<usize>::new());
+
+        // NaN is not equal to itself
+        assert_eq!(rank(&[f64::NAN, f64::NAN]), vec![0, 1]);
+        assert_eq!(rank(&[1.0, 1.0, f64::NAN]), vec![0, 0, 2]);
+    }
+}
@@ -0,0 +1,57 @@
+/// Sorts the array in place with selection sort,
+/// which moves the minimum of the unsorted suffix to its front on each pass.
+/// It takes O(n²) comparisons but at most n - 1 swaps,
+/// so it suits types that are cheap to compare but expensive to move.
+/// The sort is not stable.
+pub fn selection_sort<T: PartialOrd>(array: &mut [T]) {
+    for i in 0..array.len().saturating_sub(1) {
+        // Find the minimum of array[i..]
+        let mut min_index = i;
+        for j in (i + 1)..array.len() {
+            if array[j] < array[min_index] {
+                min_index = j;
+            }
+        }
+
+        if min_index != i {
+            array.swap(i, min_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::insertion_sort;
+
+    /// Checks that selection sort agrees with insertion sort.
+    fn assert_sorts_like_insertion_sort(array: &[i32]) {
+        let mut expected = array.to_vec();
+        insertion_sort(&mut expected);
+
+        let mut actual = array.to_vec();
+        selection_sort(&mut actual);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_selection_sort() {
+        let mut array: [i32; 0] = [];
+        selection_sort(&mut array);
+        assert!(array.is_empty());
+
+        let mut array = [5];
+        selection_sort(&mut array);
+        assert_eq!(array, [5]);
+
+        let mut array = [3, -1, 4, 1, 5, 9, 2, 6];
+        selection_sort(&mut array);
+        assert_eq!(array, [-1, 1, 2, 3, 4, 5, 6, 9]);
+
+        assert_sorts_like_insertion_sort(&[]);
+        assert_sorts_like_insertion_sort(&[1]);
+        assert_sorts_like_insertion_sort(&(0..100).rev().collect::<Vec<_>>());
+        assert_sorts_like_insertion_sort(&[2, 1, 2, 1, 1, 2, 0, 2, 1, 0, 0, 2]);
+        assert_sorts_like_insertion_sort(&[7; 10]);
+    }
+}
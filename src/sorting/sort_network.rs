@@ -0,0 +1,160 @@
+use super::{ SortOrder, insertion_sort_ordered };
+
+/// Comparators of the optimal sorting networks for 0 to 8 elements.
+const NETWORKS: [&[(usize, usize)]; 9] = [
+    &[],
+    &[],
+    &[(0, 1)],
+    &[(0, 2), (0, 1), (1, 2)],
+    &[(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)],
+    &[(0, 1), (3, 4), (2, 4), (2, 3), (1, 4), (0, 3), (0, 2), (1, 3), (1, 2)],
+    &[
+        (1, 2),
+        (4, 5),
+        (0, 2),
+        (3, 5),
+        (0, 1),
+        (3, 4),
+        (2, 5),
+        (0, 3),
+        (1, 4),
+        (2, 4),
+        (1, 3),
+        (2, 3),
+    ],
+    &[
+        (1, 2),
+        (3, 4),
+        (5, 6),
+        (0, 2),
+        (3, 5),
+        (4, 6),
+        (0, 1),
+        (4, 5),
+        (2, 6),
+        (0, 4),
+        (1, 5),
+        (0, 3),
+        (2, 5),
+        (1, 3),
+        (2, 4),
+        (2, 3),
+    ],
+    &[
+        (0, 2),
+        (1, 3),
+        (4, 6),
+        (5, 7),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+        (0, 1),
+        (2, 3),
+        (4, 5),
+        (6, 7),
+        (2, 4),
+        (3, 5),
+        (1, 4),
+        (3, 6),
+        (1, 2),
+        (3, 4),
+        (5, 6),
+    ],
+];
+
+/// Sorts tiny arrays of up to 8 elements with optimal sorting networks,
+/// whose fixed sequences of comparisons are friendly to branch prediction.
+/// Longer arrays are sorted with insertion sort.
+/// Unlike insertion sort, the networks are not stable.
+pub fn sort_network<T: PartialOrd>(array: &mut [T]) {
+    sort_network_ordered(array, SortOrder::Ascending);
+}
+
+/// Sorts tiny arrays with optimal sorting networks in the given order.
+pub fn sort_network_ordered<T: PartialOrd>(array: &mut [T], order: SortOrder) {
+    let Some(network) = NETWORKS.get(array.len()) else {
+        insertion_sort_ordered(array, order);
+        return;
+    };
+
+    for &(i, j) in network.iter() {
+        if order.is_before(&array[j], &array[i]) {
+            array.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calls `f` on every permutation of the array with Heap's algorithm.
+    fn for_each_permutation(array: &mut [i32], k: usize, f: &mut impl FnMut(&[i32])) {
+        if k <= 1 {
+            f(array);
+            return;
+        }
+
+        for i in 0..k {
+            for_each_permutation(array, k - 1, f);
+            if k.is_multiple_of(2) {
+                array.swap(i, k - 1);
+            } else {
+                array.swap(0, k - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_network() {
+        for n in 0..=8 {
+            let mut values: Vec<i32> = (0..n).collect();
+            let k = values.len();
+            for_each_permutation(&mut values, k, &mut |permutation| {
+                let mut array = permutation.to_vec();
+                sort_network(&mut array);
+                let mut expected = permutation.to_vec();
+                expected.sort();
+                assert_eq!(array, expected);
+            });
+        }
+    }
+
+    #[test]
+    fn sort_with_duplicates() {
+        // By the 0-1 principle, sorting every binary input suffices
+        for n in 0..=8 {
+            for bits in 0..1_u32 << n {
+                let mut array: Vec<u32> = (0..n).map(|i| (bits >> i) & 1).collect();
+                let mut expected = array.clone();
+                expected.sort();
+                sort_network(&mut array);
+                assert_eq!(array, expected);
+            }
+        }
+
+        let mut array = [5, 3, 9, 1, 4, 4, 8, 2, 7, 0];
+        sort_network(&mut array);
+        assert_eq!(array, [0, 1, 2, 3, 4, 4, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn sort_descending() {
+        for n in 0..=8 {
+            let mut values: Vec<i32> = (0..n).collect();
+            let k = values.len();
+            for_each_permutation(&mut values, k, &mut |permutation| {
+                let mut array = permutation.to_vec();
+                sort_network_ordered(&mut array, SortOrder::Descending);
+                let expected: Vec<i32> = (0..n).rev().collect();
+                assert_eq!(array, expected);
+            });
+        }
+
+        // Longer arrays fall back to insertion sort
+        let mut array = [5, 3, 9, 1, 4, 4, 8, 2, 7, 0];
+        sort_network_ordered(&mut array, SortOrder::Descending);
+        assert_eq!(array, [9, 8, 7, 5, 4, 4, 3, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,49 @@
+use std::cmp::Ordering;
+
+/// The order the sorting functions arrange the elements in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Checks if `a` should be placed strictly before `b`.
+    /// Equal elements are never placed before each other,
+    /// so stable sorts keep them in the input order in both directions.
+    pub fn is_before<T: PartialOrd>(self, a: &T, b: &T) -> bool {
+        match self {
+            SortOrder::Ascending => a < b,
+            SortOrder::Descending => b < a,
+        }
+    }
+
+    /// Compares the two elements in this order.
+    /// Incomparable elements are treated as equal.
+    pub fn compare<T: PartialOrd>(self, a: &T, b: &T) -> Ordering {
+        let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        match self {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_order() {
+        assert!(SortOrder::Ascending.is_before(&1, &2));
+        assert!(!SortOrder::Ascending.is_before(&2, &2));
+        assert!(SortOrder::Descending.is_before(&2, &1));
+        assert!(!SortOrder::Descending.is_before(&2, &2));
+
+        assert_eq!(SortOrder::Ascending.compare(&1, &2), Ordering::Less);
+        assert_eq!(SortOrder::Descending.compare(&1, &2), Ordering::Greater);
+        assert_eq!(SortOrder::Descending.compare(&f64::NAN, &0.0), Ordering::Equal);
+        assert_eq!(SortOrder::default(), SortOrder::Ascending);
+    }
+}
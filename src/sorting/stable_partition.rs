@@ -0,0 +1,38 @@
+/// Moves all elements satisfying the predicate to the front of the array
+/// while preserving the relative order within each group,
+/// and returns the number of elements satisfying the predicate.
+pub fn stable_partition<T, F: Fn(&T) -> bool>(array: &mut [T], pred: F) -> usize {
+    // Elements in [0, k) satisfy the predicate
+    let mut k = 0;
+
+    for j in 0..array.len() {
+        if !pred(&array[j]) {
+            continue;
+        }
+
+        // Move the element right after the satisfying ones by rotating part of the array
+        if k < j {
+            array[k..=j].rotate_right(1);
+        }
+
+        k += 1;
+    }
+
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_partition() {
+        let mut array = [5, 2, 7, 4, 1, 8, 6, 3];
+        let k = stable_partition(&mut array, |value| value % 2 == 0);
+        assert_eq!(k, 4);
+        assert_eq!(array, [2, 4, 8, 6, 5, 7, 1, 3]);
+
+        let mut array: [i32; 0] = [];
+        assert_eq!(stable_partition(&mut array, |value| value % 2 == 0), 0);
+    }
+}